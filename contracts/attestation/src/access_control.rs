@@ -0,0 +1,63 @@
+//! Role-based access control and the pause switch.
+//!
+//! Roles are stored as a bitmap per address so an account can hold
+//! several roles at once; the bitmap storage itself lives in the shared
+//! [`crate::roles`] helper, namespaced so it can't collide with
+//! `permission_control`'s separate governance roles.
+
+use crate::roles::{self, Namespace};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+pub const ROLE_ADMIN: u32 = 1 << 0;
+pub const ROLE_ATTESTOR: u32 = 1 << 1;
+pub const ROLE_BUSINESS: u32 = 1 << 2;
+pub const ROLE_OPERATOR: u32 = 1 << 3;
+/// Authorized to set KYC/identity verification status for businesses.
+pub const ROLE_KYC_PROVIDER: u32 = 1 << 4;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Paused,
+}
+
+pub fn get_roles(env: &Env, account: &Address) -> u32 {
+    roles::get_roles(env, &Namespace::AccessControl, account)
+}
+
+pub fn has_role(env: &Env, account: &Address, role: u32) -> bool {
+    roles::has_role(env, &Namespace::AccessControl, account, role)
+}
+
+pub fn grant_role(env: &Env, account: &Address, role: u32) {
+    roles::grant_role(env, &Namespace::AccessControl, account, role);
+}
+
+pub fn revoke_role(env: &Env, account: &Address, role: u32) {
+    roles::revoke_role(env, &Namespace::AccessControl, account, role);
+}
+
+pub fn get_role_holders(env: &Env) -> Vec<Address> {
+    roles::get_role_holders(env, &Namespace::AccessControl)
+}
+
+/// Require that `caller` authorized the call and holds `ROLE_ADMIN`.
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+    assert!(
+        has_role(env, caller, ROLE_ADMIN),
+        "caller must have ADMIN role"
+    );
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+}
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+pub fn require_not_paused(env: &Env) {
+    assert!(!is_paused(env), "contract is paused");
+}
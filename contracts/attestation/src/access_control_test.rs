@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Env;
+
+fn setup() -> (Env, AttestationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+#[test]
+fn admin_holds_role_after_initialize() {
+    let (_env, client, admin) = setup();
+    assert!(client.has_role(&admin, &ROLE_ADMIN));
+}
+
+#[test]
+fn grant_and_revoke_role() {
+    let (env, client, admin) = setup();
+    let attestor = Address::generate(&env);
+
+    assert!(!client.has_role(&attestor, &ROLE_ATTESTOR));
+    client.grant_role(&admin, &attestor, &ROLE_ATTESTOR);
+    assert!(client.has_role(&attestor, &ROLE_ATTESTOR));
+
+    client.revoke_role(&admin, &attestor, &ROLE_ATTESTOR);
+    assert!(!client.has_role(&attestor, &ROLE_ATTESTOR));
+}
+
+#[test]
+fn role_holders_reflects_grants_and_revokes() {
+    let (env, client, admin) = setup();
+    let business = Address::generate(&env);
+
+    client.grant_role(&admin, &business, &ROLE_BUSINESS);
+    let holders = client.get_role_holders();
+    assert!(holders.iter().any(|h| h == business));
+
+    client.revoke_role(&admin, &business, &ROLE_BUSINESS);
+    let holders = client.get_role_holders();
+    assert!(!holders.iter().any(|h| h == business));
+}
+
+#[test]
+fn pause_blocks_attestation_submission() {
+    let (env, client, admin) = setup();
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+
+    let result = client.try_submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+    assert!(result.is_err());
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+}
@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Env;
+
+fn setup() -> (Env, AttestationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+fn item(env: &Env, business: &Address, period: &str, seed: u8) -> BatchAttestationItem {
+    BatchAttestationItem {
+        business: business.clone(),
+        period: String::from_str(env, period),
+        merkle_root: BytesN::from_array(env, &[seed; 32]),
+        timestamp: 1_700_000_000u64,
+        version: 1u32,
+    }
+}
+
+#[test]
+fn submits_every_item_in_the_batch() {
+    let (env, client, _admin) = setup();
+    let business_a = Address::generate(&env);
+    let business_b = Address::generate(&env);
+
+    let mut items = Vec::new(&env);
+    items.push_back(item(&env, &business_a, "2026-01", 1));
+    items.push_back(item(&env, &business_b, "2026-01", 2));
+    client.submit_attestations_batch(&items);
+
+    assert!(client.get_attestation(&business_a, &String::from_str(&env, "2026-01")).is_some());
+    assert!(client.get_attestation(&business_b, &String::from_str(&env, "2026-01")).is_some());
+}
+
+#[test]
+fn rejects_empty_batch() {
+    let (env, client, _admin) = setup();
+    let items: Vec<BatchAttestationItem> = Vec::new(&env);
+    let result = client.try_submit_attestations_batch(&items);
+    assert!(result.is_err());
+}
+
+#[test]
+fn rejects_duplicate_business_period_within_batch() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+
+    let mut items = Vec::new(&env);
+    items.push_back(item(&env, &business, "2026-01", 1));
+    items.push_back(item(&env, &business, "2026-01", 2));
+
+    let result = client.try_submit_attestations_batch(&items);
+    assert!(result.is_err());
+}
+
+#[test]
+fn rejects_whole_batch_when_any_item_fails_kyc() {
+    let (env, client, admin) = setup();
+    let kyc_provider = Address::generate(&env);
+    client.grant_role(&admin, &kyc_provider, &ROLE_KYC_PROVIDER);
+    client.set_require_kyc_mode(&admin, &true);
+
+    let verified_business = Address::generate(&env);
+    client.set_kyc_status(
+        &kyc_provider,
+        &verified_business,
+        &KycStatus::Verified,
+        &2_000_000_000u64,
+    );
+    let unverified_business = Address::generate(&env);
+
+    let mut items = Vec::new(&env);
+    items.push_back(item(&env, &verified_business, "2026-01", 1));
+    items.push_back(item(&env, &unverified_business, "2026-01", 2));
+
+    let result = client.try_submit_attestations_batch(&items);
+    assert!(result.is_err());
+    assert!(client
+        .get_attestation(&verified_business, &String::from_str(&env, "2026-01"))
+        .is_none());
+}
+
+#[test]
+fn rejects_item_colliding_with_existing_attestation() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+
+    client.submit_attestation(
+        &business,
+        &period,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &1_700_000_000u64,
+        &1u32,
+    );
+
+    let mut items = Vec::new(&env);
+    items.push_back(item(&env, &business, "2026-01", 2));
+    let result = client.try_submit_attestations_batch(&items);
+    assert!(result.is_err());
+}
@@ -0,0 +1,126 @@
+//! Delegated attestation submission via off-chain signed payloads.
+//!
+//! Lets a relayer post an attestation on behalf of a business that has
+//! pre-authorized a signing key, instead of requiring the business to
+//! `require_auth` the Soroban envelope itself. The signed digest follows a
+//! domain-separated, EIP-712-style layout: a fixed domain tag plus every
+//! field of the attestation, folded together with a per-business nonce so
+//! a captured signature can't be replayed.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, String};
+
+const DOMAIN_TAG: &str = "VERITASOR_ATTESTATION";
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    SignerKey(Address),
+    Nonce(Address),
+    StaleWindow,
+}
+
+/// Authorize `signer_pubkey` to sign delegated attestations on behalf of
+/// `business`. Must be called by `business` itself.
+pub fn set_authorized_signer(env: &Env, business: &Address, signer_pubkey: &BytesN<32>) {
+    business.require_auth();
+    env.storage()
+        .instance()
+        .set(&DataKey::SignerKey(business.clone()), signer_pubkey);
+}
+
+/// Return `business`'s authorized signing key, if any.
+pub fn get_authorized_signer(env: &Env, business: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SignerKey(business.clone()))
+}
+
+pub fn get_nonce(env: &Env, business: &Address) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Nonce(business.clone()))
+        .unwrap_or(0)
+}
+
+fn increment_nonce(env: &Env, business: &Address) {
+    let nonce = get_nonce(env, business);
+    env.storage()
+        .instance()
+        .set(&DataKey::Nonce(business.clone()), &(nonce + 1));
+}
+
+/// Set the allowed staleness window (seconds) for delegated submissions'
+/// `timestamp` argument, measured against the current ledger time.
+pub fn set_stale_window(env: &Env, seconds: u64) {
+    env.storage().instance().set(&DataKey::StaleWindow, &seconds);
+}
+
+/// Return the configured staleness window. Defaults to `u64::MAX`
+/// (no staleness check) until an admin configures one.
+pub fn get_stale_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StaleWindow)
+        .unwrap_or(u64::MAX)
+}
+
+/// Build the canonical digest for a delegated attestation: the domain tag,
+/// this contract's address, and every attestation field, folded with
+/// `business`'s current nonce to prevent replay.
+#[allow(clippy::too_many_arguments)]
+fn build_digest(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    merkle_root: &BytesN<32>,
+    timestamp: u64,
+    version: u32,
+    nonce: u64,
+) -> BytesN<32> {
+    let domain = (
+        env.current_contract_address(),
+        String::from_str(env, DOMAIN_TAG),
+        business.clone(),
+        period.clone(),
+        merkle_root.clone(),
+        timestamp,
+        version,
+        nonce,
+    );
+    let encoded = domain.to_xdr(env);
+    env.crypto().sha256(&encoded).into()
+}
+
+/// Verify a delegated attestation's signature and staleness, then
+/// advance `business`'s nonce so the signature can't be replayed.
+///
+/// Panics if no signer is authorized for `business`, `signer_pubkey`
+/// doesn't match it, `timestamp` falls outside the configured staleness
+/// window, or the signature doesn't verify against the computed digest.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_and_consume(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    merkle_root: &BytesN<32>,
+    timestamp: u64,
+    version: u32,
+    signer_pubkey: &BytesN<32>,
+    signature: &BytesN<64>,
+) {
+    let authorized = get_authorized_signer(env, business).expect("no signer authorized");
+    assert!(*signer_pubkey == authorized, "signer_pubkey is not authorized for business");
+
+    let now = env.ledger().timestamp();
+    let window = get_stale_window(env);
+    let age = now.abs_diff(timestamp);
+    assert!(age <= window, "timestamp is outside the allowed staleness window");
+
+    let nonce = get_nonce(env, business);
+    let digest = build_digest(env, business, period, merkle_root, timestamp, version, nonce);
+
+    env.crypto()
+        .ed25519_verify(signer_pubkey, &Bytes::from_array(env, &digest.to_array()), signature);
+
+    increment_nonce(env, business);
+}
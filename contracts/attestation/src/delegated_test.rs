@@ -0,0 +1,310 @@
+#![cfg(test)]
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{xdr::ToXdr, Env};
+
+fn setup() -> (Env, AttestationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn pubkey_of(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+/// Mirrors `delegated::build_digest` so tests can produce a signature over
+/// exactly what the contract will verify against.
+#[allow(clippy::too_many_arguments)]
+fn digest(
+    env: &Env,
+    contract: &Address,
+    business: &Address,
+    period: &String,
+    merkle_root: &BytesN<32>,
+    timestamp: &u64,
+    version: &u32,
+    nonce: u64,
+) -> BytesN<32> {
+    let domain = (
+        contract.clone(),
+        String::from_str(env, "VERITASOR_ATTESTATION"),
+        business.clone(),
+        period.clone(),
+        merkle_root.clone(),
+        *timestamp,
+        *version,
+        nonce,
+    );
+    let encoded = domain.to_xdr(env);
+    env.crypto().sha256(&encoded).into()
+}
+
+fn sign(key: &SigningKey, digest: &BytesN<32>, env: &Env) -> BytesN<64> {
+    let signature = key.sign(&digest.to_array());
+    BytesN::from_array(env, &signature.to_bytes())
+}
+
+#[test]
+fn valid_signed_attestation_is_accepted() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let key = signing_key(1);
+    client.set_authorized_signer(&business, &pubkey_of(&env, &key));
+
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    let d = digest(&env, &client.address, &business, &period, &root, &1_700_000_000u64, &1u32, 0);
+    let signature = sign(&key, &d, &env);
+
+    client.submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &signature,
+    );
+
+    assert!(client.get_attestation(&business, &period).is_some());
+}
+
+#[test]
+fn tampered_signature_is_rejected() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let key = signing_key(1);
+    client.set_authorized_signer(&business, &pubkey_of(&env, &key));
+
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    let d = digest(&env, &client.address, &business, &period, &root, &1_700_000_000u64, &1u32, 0);
+    let mut signature = sign(&key, &d, &env).to_array();
+    signature[0] ^= 0xff;
+    let signature = BytesN::from_array(&env, &signature);
+
+    let result = client.try_submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &signature,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn signer_pubkey_not_matching_the_authorized_key_is_rejected() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let authorized = signing_key(1);
+    let impostor = signing_key(2);
+    client.set_authorized_signer(&business, &pubkey_of(&env, &authorized));
+
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    let d = digest(&env, &client.address, &business, &period, &root, &1_700_000_000u64, &1u32, 0);
+    let signature = sign(&impostor, &d, &env);
+
+    let result = client.try_submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &impostor),
+        &signature,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn stale_timestamp_outside_the_configured_window_is_rejected() {
+    let (env, client, admin) = setup();
+    client.set_delegation_stale_window(&admin, &3_600u64);
+    env.ledger().with_mut(|l| l.timestamp = 1_700_010_000);
+
+    let business = Address::generate(&env);
+    let key = signing_key(1);
+    client.set_authorized_signer(&business, &pubkey_of(&env, &key));
+
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    let stale_timestamp = 1_700_000_000u64; // more than 3600s before ledger time
+    let d = digest(&env, &client.address, &business, &period, &root, &stale_timestamp, &1u32, 0);
+    let signature = sign(&key, &d, &env);
+
+    let result = client.try_submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &stale_timestamp,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &signature,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn replaying_the_same_signed_payload_twice_is_rejected() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let key = signing_key(1);
+    client.set_authorized_signer(&business, &pubkey_of(&env, &key));
+
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    let d = digest(&env, &client.address, &business, &period, &root, &1_700_000_000u64, &1u32, 0);
+    let signature = sign(&key, &d, &env);
+
+    client.submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &signature,
+    );
+
+    // Same signed payload replayed after the nonce has already advanced to
+    // 1: the digest it was signed over no longer matches, so it must not
+    // verify again.
+    let result = client.try_submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &signature,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn signed_submission_with_fees_enabled_needs_no_business_authorization() {
+    let (env, client, admin) = setup();
+
+    let mut owners = Vec::new(&env);
+    owners.push_back(admin.clone());
+    client.initialize_multisig(&admin, &owners, &1);
+
+    let token = Address::generate(&env);
+    let collector = Address::generate(&env);
+    client.propose_fee_config(
+        &admin,
+        &FeeConfig {
+            token,
+            collector,
+            base_fee: 1_000,
+            enabled: true,
+        },
+    );
+    client.approve_proposal(&admin, &0);
+    client.execute_fee_config(&admin, &0);
+
+    let business = Address::generate(&env);
+    let key = signing_key(1);
+    client.set_authorized_signer(&business, &pubkey_of(&env, &key));
+
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    let d = digest(&env, &client.address, &business, &period, &root, &1_700_000_000u64, &1u32, 0);
+    let signature = sign(&key, &d, &env);
+
+    // Switch off the blanket auth mock and require that this exact call
+    // succeed with zero authorization entries -- proving the delegated
+    // path never asks `business` (or anyone else) to sign the Soroban
+    // envelope, even though a `FeeConfig` is enabled.
+    env.set_auths(&[]);
+    client.submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &signature,
+    );
+
+    let (_, _, _, fee_paid) = client.get_attestation(&business, &period).unwrap();
+    assert_eq!(fee_paid, 0);
+}
+
+#[test]
+fn digest_is_domain_separated_by_business_period_and_contract_address() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let other_business = Address::generate(&env);
+    let key = signing_key(1);
+    client.set_authorized_signer(&business, &pubkey_of(&env, &key));
+    client.set_authorized_signer(&other_business, &pubkey_of(&env, &key));
+
+    let period = String::from_str(&env, "2026-01");
+    let other_period = String::from_str(&env, "2026-02");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+
+    // Signature built for `other_business` must not verify for `business`.
+    let wrong_business_digest =
+        digest(&env, &client.address, &other_business, &period, &root, &1_700_000_000u64, &1u32, 0);
+    let wrong_business_signature = sign(&key, &wrong_business_digest, &env);
+    let result = client.try_submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &wrong_business_signature,
+    );
+    assert!(result.is_err());
+
+    // Signature built for a different period must not verify either.
+    let wrong_period_digest =
+        digest(&env, &client.address, &business, &other_period, &root, &1_700_000_000u64, &1u32, 0);
+    let wrong_period_signature = sign(&key, &wrong_period_digest, &env);
+    let result = client.try_submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &wrong_period_signature,
+    );
+    assert!(result.is_err());
+
+    // Signature built for a different contract address must not verify
+    // against this deployment.
+    let other_contract_id = env.register(AttestationContract, ());
+    let wrong_contract_digest =
+        digest(&env, &other_contract_id, &business, &period, &root, &1_700_000_000u64, &1u32, 0);
+    let wrong_contract_signature = sign(&key, &wrong_contract_digest, &env);
+    let result = client.try_submit_attestation_signed(
+        &business,
+        &period,
+        &root,
+        &1_700_000_000u64,
+        &1u32,
+        &pubkey_of(&env, &key),
+        &wrong_contract_signature,
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,239 @@
+//! Fee configuration, tier/volume discounting, and fee collection.
+
+use soroban_sdk::{contracttype, token, Address, Env, String, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    FeeConfig,
+    TierDiscount(u32),
+    BusinessTier(Address),
+    VolumeThresholds,
+    VolumeDiscounts,
+    BusinessCount(Address),
+    Attestation(Address, String),
+    Revoked(Address, String),
+    FeeSplitRecipients,
+    FeeSplitWeights,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeConfig {
+    pub token: Address,
+    pub collector: Address,
+    pub base_fee: i128,
+    pub enabled: bool,
+}
+
+/// Apply a basis-point discount (0-10 000) to `base_fee`.
+pub fn compute_fee(base_fee: i128, discount_bps: u32) -> i128 {
+    let discount_bps = discount_bps.min(10_000) as i128;
+    base_fee - (base_fee * discount_bps) / 10_000
+}
+
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("not initialized")
+}
+
+/// Require that the caller authorized is the admin, returning it.
+pub fn require_admin(env: &Env) -> Address {
+    let admin = get_admin(env);
+    admin.require_auth();
+    admin
+}
+
+pub fn set_fee_config(env: &Env, config: &FeeConfig) {
+    env.storage().instance().set(&DataKey::FeeConfig, config);
+}
+
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&DataKey::FeeConfig)
+}
+
+pub fn set_tier_discount(env: &Env, tier: u32, discount_bps: u32) {
+    assert!(discount_bps <= 10_000, "discount_bps must be 0-10000");
+    env.storage()
+        .instance()
+        .set(&DataKey::TierDiscount(tier), &discount_bps);
+}
+
+pub fn get_tier_discount(env: &Env, tier: u32) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TierDiscount(tier))
+        .unwrap_or(0)
+}
+
+pub fn set_business_tier(env: &Env, business: &Address, tier: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::BusinessTier(business.clone()), &tier);
+}
+
+pub fn get_business_tier(env: &Env, business: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BusinessTier(business.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_volume_brackets(env: &Env, thresholds: &Vec<u64>, discounts: &Vec<u32>) {
+    assert!(
+        thresholds.len() == discounts.len(),
+        "thresholds and discounts must have equal length"
+    );
+    for i in 1..thresholds.len() {
+        assert!(
+            thresholds.get(i).unwrap() > thresholds.get(i - 1).unwrap(),
+            "thresholds must be strictly ascending"
+        );
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::VolumeThresholds, thresholds);
+    env.storage()
+        .instance()
+        .set(&DataKey::VolumeDiscounts, discounts);
+}
+
+fn get_volume_discount(env: &Env, count: u64) -> u32 {
+    let thresholds: Vec<u64> = env
+        .storage()
+        .instance()
+        .get(&DataKey::VolumeThresholds)
+        .unwrap_or(Vec::new(env));
+    let discounts: Vec<u32> = env
+        .storage()
+        .instance()
+        .get(&DataKey::VolumeDiscounts)
+        .unwrap_or(Vec::new(env));
+
+    let mut best = 0u32;
+    for i in 0..thresholds.len() {
+        if count >= thresholds.get(i).unwrap() {
+            best = discounts.get(i).unwrap();
+        }
+    }
+    best
+}
+
+pub fn get_business_count(env: &Env, business: &Address) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::BusinessCount(business.clone()))
+        .unwrap_or(0)
+}
+
+pub fn increment_business_count(env: &Env, business: &Address) {
+    let count = get_business_count(env, business);
+    env.storage()
+        .instance()
+        .set(&DataKey::BusinessCount(business.clone()), &(count + 1));
+}
+
+/// Calculate the fee a business would pay for its next attestation,
+/// applying the better of its tier discount and volume discount.
+pub fn calculate_fee(env: &Env, business: &Address) -> i128 {
+    let config = match get_fee_config(env) {
+        Some(config) if config.enabled => config,
+        _ => return 0,
+    };
+
+    let tier = get_business_tier(env, business);
+    let tier_discount = get_tier_discount(env, tier);
+    let count = get_business_count(env, business);
+    let volume_discount = get_volume_discount(env, count);
+
+    compute_fee(config.base_fee, tier_discount.max(volume_discount))
+}
+
+/// Configure weighted fee splitting across multiple recipients.
+///
+/// `weights` are in basis points and must sum to exactly 10 000.
+/// `recipients` and `weights` must be equal-length and non-empty.
+/// Once configured, `collect_fee` distributes the charged fee across
+/// `recipients` instead of paying the single `FeeConfig::collector`.
+pub fn set_fee_splits(env: &Env, recipients: &Vec<Address>, weights: &Vec<u32>) {
+    assert!(!recipients.is_empty(), "recipients must not be empty");
+    assert!(
+        recipients.len() == weights.len(),
+        "recipients and weights must have equal length"
+    );
+    let total: u32 = weights.iter().sum();
+    assert!(total == 10_000, "weights must sum to 10000 bps");
+
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeSplitRecipients, recipients);
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeSplitWeights, weights);
+}
+
+/// Return the configured split `(recipients, weights)`, if any.
+pub fn get_fee_splits(env: &Env) -> Option<(Vec<Address>, Vec<u32>)> {
+    let recipients: Vec<Address> = env.storage().instance().get(&DataKey::FeeSplitRecipients)?;
+    let weights: Vec<u32> = env.storage().instance().get(&DataKey::FeeSplitWeights)?;
+    Some((recipients, weights))
+}
+
+/// Calculate and transfer the fee for `business`'s next attestation from
+/// `business` to the configured collector, or to the configured split
+/// recipients if `set_fee_splits` has been called. Returns 0 without
+/// transferring anything if fees are disabled or unconfigured.
+///
+/// Each recipient is paid `fee * weight / 10 000`; any rounding dust from
+/// integer division is assigned to the first recipient so the amounts
+/// always sum to the full charged fee.
+pub fn collect_fee(env: &Env, business: &Address) -> i128 {
+    let config = match get_fee_config(env) {
+        Some(config) if config.enabled => config,
+        _ => return 0,
+    };
+
+    let fee = calculate_fee(env, business);
+    if fee > 0 {
+        let token_client = token::Client::new(env, &config.token);
+
+        match get_fee_splits(env) {
+            Some((recipients, weights)) => {
+                let mut distributed = 0i128;
+                let mut amounts = Vec::new(env);
+                for i in 0..recipients.len() {
+                    let amount = (fee * weights.get(i).unwrap() as i128) / 10_000;
+                    distributed += amount;
+                    amounts.push_back(amount);
+                }
+                let dust = fee - distributed;
+                let first = amounts.get(0).unwrap() + dust;
+                amounts.set(0, first);
+
+                for i in 0..recipients.len() {
+                    let recipient = recipients.get(i).unwrap();
+                    let amount = amounts.get(i).unwrap();
+                    if amount > 0 {
+                        token_client.transfer(business, &recipient, &amount);
+                        crate::events::emit_fee_distributed(env, business, &recipient, amount);
+                    }
+                }
+            }
+            None => {
+                token_client.transfer(business, &config.collector, &fee);
+            }
+        }
+    }
+    fee
+}
@@ -0,0 +1,267 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Env};
+
+fn create_token(env: &Env, admin: &Address) -> (Address, token::Client<'static>, token::StellarAssetClient<'static>) {
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    let address = sac.address();
+    (
+        address.clone(),
+        token::Client::new(env, &address),
+        token::StellarAssetClient::new(env, &address),
+    )
+}
+
+fn setup() -> (Env, AttestationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let mut owners = Vec::new(&env);
+    owners.push_back(admin.clone());
+    client.initialize_multisig(&admin, &owners, &1);
+
+    (env, client, admin)
+}
+
+#[test]
+fn compute_fee_applies_basis_point_discount() {
+    assert_eq!(compute_fee(10_000, 2_500), 7_500);
+    assert_eq!(compute_fee(10_000, 0), 10_000);
+    assert_eq!(compute_fee(10_000, 10_000), 0);
+}
+
+#[test]
+fn tier_discount_lowers_fee_quote() {
+    let (env, client, admin) = setup();
+    let business = Address::generate(&env);
+    let token = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.propose_fee_config(
+        &admin,
+        &FeeConfig {
+            token,
+            collector,
+            base_fee: 1_000,
+            enabled: true,
+        },
+    );
+    client.approve_proposal(&admin, &0);
+    client.execute_fee_config(&admin, &0);
+
+    assert_eq!(client.get_fee_quote(&business), 1_000);
+
+    client.propose_business_tier(&admin, &business, &1);
+    client.approve_proposal(&admin, &1);
+    client.execute_business_tier(&admin, &1);
+    client.propose_tier_discount(&admin, &1, &2_000);
+    client.approve_proposal(&admin, &2);
+    client.execute_tier_discount(&admin, &2);
+    assert_eq!(client.get_fee_quote(&business), 800);
+}
+
+#[test]
+fn volume_brackets_apply_the_best_matching_discount() {
+    let (env, client, admin) = setup();
+    let business = Address::generate(&env);
+    let token = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.propose_fee_config(
+        &admin,
+        &FeeConfig {
+            token,
+            collector,
+            base_fee: 1_000,
+            enabled: true,
+        },
+    );
+    client.approve_proposal(&admin, &0);
+    client.execute_fee_config(&admin, &0);
+
+    let mut thresholds = Vec::new(&env);
+    thresholds.push_back(3u64);
+    let mut discounts = Vec::new(&env);
+    discounts.push_back(1_000u32);
+    client.propose_volume_brackets(&admin, &thresholds, &discounts);
+    client.approve_proposal(&admin, &1);
+    client.execute_volume_brackets(&admin, &1);
+
+    assert_eq!(client.get_fee_quote(&business), 1_000);
+
+    env.as_contract(&client.address, || {
+        for _ in 0..3 {
+            dynamic_fees::increment_business_count(&env, &business);
+        }
+    });
+
+    assert_eq!(client.get_fee_quote(&business), 900);
+}
+
+#[test]
+fn execute_volume_brackets_rejects_mismatched_lengths() {
+    let (env, client, admin) = setup();
+    let mut thresholds = Vec::new(&env);
+    thresholds.push_back(10u64);
+    let discounts: Vec<u32> = Vec::new(&env);
+
+    client.propose_volume_brackets(&admin, &thresholds, &discounts);
+    client.approve_proposal(&admin, &0);
+    let result = client.try_execute_volume_brackets(&admin, &0);
+    assert!(result.is_err());
+}
+
+#[test]
+fn fee_splits_round_trip() {
+    let (env, client, admin) = setup();
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(r1.clone());
+    recipients.push_back(r2.clone());
+    let mut weights = Vec::new(&env);
+    weights.push_back(4_000u32);
+    weights.push_back(6_000u32);
+
+    client.propose_fee_splits(&admin, &recipients, &weights);
+    client.approve_proposal(&admin, &0);
+    client.execute_fee_splits(&admin, &0);
+    let (got_recipients, got_weights) = client.get_fee_splits().expect("splits missing");
+    assert_eq!(got_recipients, recipients);
+    assert_eq!(got_weights, weights);
+}
+
+#[test]
+fn fee_split_distributes_real_transfers_with_first_recipient_absorbing_dust() {
+    let (env, client, admin) = setup();
+    let business = Address::generate(&env);
+    let (token_address, token_client, token_admin) = create_token(&env, &admin);
+
+    token_admin.mint(&business, &1_000i128);
+
+    let collector = Address::generate(&env);
+    client.propose_fee_config(
+        &admin,
+        &FeeConfig {
+            token: token_address,
+            collector,
+            base_fee: 100,
+            enabled: true,
+        },
+    );
+    client.approve_proposal(&admin, &0);
+    client.execute_fee_config(&admin, &0);
+
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(r1.clone());
+    recipients.push_back(r2.clone());
+    recipients.push_back(r3.clone());
+    let mut weights = Vec::new(&env);
+    // 100 * 3334 / 10000 = 33, 100 * 3333 / 10000 = 33, 100 * 3333 / 10000 = 33
+    // -> 99 distributed, 1 of dust goes to the first recipient.
+    weights.push_back(3_334u32);
+    weights.push_back(3_333u32);
+    weights.push_back(3_333u32);
+    client.propose_fee_splits(&admin, &recipients, &weights);
+    client.approve_proposal(&admin, &1);
+    client.execute_fee_splits(&admin, &1);
+
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[7u8; 32]);
+    client.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    assert_eq!(token_client.balance(&business), 900);
+    assert_eq!(token_client.balance(&r1), 34);
+    assert_eq!(token_client.balance(&r2), 33);
+    assert_eq!(token_client.balance(&r3), 33);
+}
+
+#[test]
+fn execute_fee_splits_rejects_weights_not_summing_to_10000() {
+    let (env, client, admin) = setup();
+    let r1 = Address::generate(&env);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(r1);
+    let mut weights = Vec::new(&env);
+    weights.push_back(9_000u32);
+
+    client.propose_fee_splits(&admin, &recipients, &weights);
+    client.approve_proposal(&admin, &0);
+    let result = client.try_execute_fee_splits(&admin, &0);
+    assert!(result.is_err());
+}
+
+#[test]
+fn fee_config_only_takes_effect_after_governance_execution() {
+    let (env, client, admin) = setup();
+    let token = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    client.propose_fee_config(
+        &admin,
+        &FeeConfig {
+            token: token.clone(),
+            collector: collector.clone(),
+            base_fee: 500,
+            enabled: true,
+        },
+    );
+    assert!(client.get_fee_config().is_none());
+
+    client.approve_proposal(&admin, &0);
+    client.execute_fee_config(&admin, &0);
+    let config = client.get_fee_config().expect("fee config missing");
+    assert_eq!(config.base_fee, 500);
+    assert!(config.enabled);
+}
+
+#[test]
+fn propose_fee_config_requires_proposer_role() {
+    let (env, client, _admin) = setup();
+    let outsider = Address::generate(&env);
+    let token = Address::generate(&env);
+    let collector = Address::generate(&env);
+
+    let result = client.try_propose_fee_config(
+        &outsider,
+        &FeeConfig {
+            token,
+            collector,
+            base_fee: 500,
+            enabled: true,
+        },
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn business_tier_change_requires_an_approved_proposal() {
+    // A compromised admin key alone must not be able to move fees: holding
+    // ROLE_FEE_MANAGER (granted to every admin at `initialize`) is no
+    // longer sufficient on its own, since there's no direct setter left to
+    // call -- only the propose/execute multisig flow can change a
+    // business's tier.
+    let (env, client, admin) = setup();
+    let business = Address::generate(&env);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_propose_business_tier(&outsider, &business, &1);
+    assert!(result.is_err());
+
+    let proposal_id = client.propose_business_tier(&admin, &business, &1);
+    client.approve_proposal(&admin, &proposal_id);
+    client.execute_business_tier(&admin, &proposal_id);
+    assert_eq!(client.get_business_tier(&business), 1);
+}
@@ -0,0 +1,227 @@
+//! Event payloads and emission helpers.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationSubmittedEvent {
+    pub business: Address,
+    pub period: String,
+    pub merkle_root: BytesN<32>,
+    pub timestamp: u64,
+    pub version: u32,
+    pub fee_paid: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationRevokedEvent {
+    pub business: Address,
+    pub period: String,
+    pub caller: Address,
+    pub reason: String,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationMigratedEvent {
+    pub business: Address,
+    pub period: String,
+    pub old_merkle_root: BytesN<32>,
+    pub new_merkle_root: BytesN<32>,
+    pub old_version: u32,
+    pub new_version: u32,
+    pub caller: Address,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn emit_attestation_submitted(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    merkle_root: &BytesN<32>,
+    timestamp: u64,
+    version: u32,
+    fee_paid: i128,
+) {
+    let event = AttestationSubmittedEvent {
+        business: business.clone(),
+        period: period.clone(),
+        merkle_root: merkle_root.clone(),
+        timestamp,
+        version,
+        fee_paid,
+    };
+    env.events()
+        .publish((Symbol::new(env, "attestation_submitted"),), event);
+}
+
+pub fn emit_attestation_revoked(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    caller: &Address,
+    reason: &String,
+) {
+    let event = AttestationRevokedEvent {
+        business: business.clone(),
+        period: period.clone(),
+        caller: caller.clone(),
+        reason: reason.clone(),
+    };
+    env.events()
+        .publish((Symbol::new(env, "attestation_revoked"),), event);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn emit_attestation_migrated(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    old_merkle_root: &BytesN<32>,
+    new_merkle_root: &BytesN<32>,
+    old_version: u32,
+    new_version: u32,
+    caller: &Address,
+) {
+    let event = AttestationMigratedEvent {
+        business: business.clone(),
+        period: period.clone(),
+        old_merkle_root: old_merkle_root.clone(),
+        new_merkle_root: new_merkle_root.clone(),
+        old_version,
+        new_version,
+        caller: caller.clone(),
+    };
+    env.events()
+        .publish((Symbol::new(env, "attestation_migrated"),), event);
+}
+
+pub fn emit_role_granted(env: &Env, account: &Address, role: u32, caller: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "role_granted"), account.clone()),
+        (role, caller.clone()),
+    );
+}
+
+pub fn emit_role_revoked(env: &Env, account: &Address, role: u32, caller: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "role_revoked"), account.clone()),
+        (role, caller.clone()),
+    );
+}
+
+pub fn emit_paused(env: &Env, caller: &Address) {
+    env.events()
+        .publish((Symbol::new(env, "paused"),), caller.clone());
+}
+
+pub fn emit_unpaused(env: &Env, caller: &Address) {
+    env.events()
+        .publish((Symbol::new(env, "unpaused"),), caller.clone());
+}
+
+pub fn emit_kyc_status_changed(
+    env: &Env,
+    business: &Address,
+    status: &crate::kyc::KycStatus,
+    expiry: u64,
+    caller: &Address,
+) {
+    env.events().publish(
+        (Symbol::new(env, "kyc_status_changed"), business.clone()),
+        (status.clone(), expiry, caller.clone()),
+    );
+}
+
+pub fn emit_fee_distributed(env: &Env, business: &Address, recipient: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "fee_distributed"), business.clone()),
+        (recipient.clone(), amount),
+    );
+}
+
+pub fn emit_proposal_queued(env: &Env, proposal_id: u64, approved_at: u64) {
+    env.events().publish(
+        (Symbol::new(env, "proposal_queued"), proposal_id),
+        approved_at,
+    );
+}
+
+pub fn emit_proposal_cancelled(env: &Env, proposal_id: u64, caller: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "proposal_cancelled"), proposal_id),
+        caller.clone(),
+    );
+}
+
+pub fn emit_call_executed(
+    env: &Env,
+    proposal_id: u64,
+    target: &Address,
+    function: &Symbol,
+    caller: &Address,
+) {
+    env.events().publish(
+        (Symbol::new(env, "call_executed"), proposal_id),
+        (target.clone(), function.clone(), caller.clone()),
+    );
+}
+
+pub fn emit_fee_config_changed(
+    env: &Env,
+    token: &Address,
+    collector: &Address,
+    base_fee: i128,
+    enabled: bool,
+    caller: &Address,
+) {
+    env.events().publish(
+        (Symbol::new(env, "fee_config_changed"),),
+        (
+            token.clone(),
+            collector.clone(),
+            base_fee,
+            enabled,
+            caller.clone(),
+        ),
+    );
+}
+
+pub fn emit_tier_discount_changed(env: &Env, tier: u32, discount_bps: u32, caller: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "tier_discount_changed"), tier),
+        (discount_bps, caller.clone()),
+    );
+}
+
+pub fn emit_business_tier_changed(env: &Env, business: &Address, tier: u32, caller: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "business_tier_changed"), business.clone()),
+        (tier, caller.clone()),
+    );
+}
+
+pub fn emit_volume_brackets_changed(
+    env: &Env,
+    thresholds: &Vec<u64>,
+    discounts: &Vec<u32>,
+    caller: &Address,
+) {
+    env.events().publish(
+        (Symbol::new(env, "volume_brackets_changed"),),
+        (thresholds.clone(), discounts.clone(), caller.clone()),
+    );
+}
+
+pub fn emit_fee_splits_changed(
+    env: &Env,
+    recipients: &Vec<Address>,
+    weights: &Vec<u32>,
+    caller: &Address,
+) {
+    env.events().publish(
+        (Symbol::new(env, "fee_splits_changed"),),
+        (recipients.clone(), weights.clone(), caller.clone()),
+    );
+}
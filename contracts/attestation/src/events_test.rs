@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Env;
+
+fn setup() -> (Env, AttestationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+#[test]
+fn submit_attestation_emits_submitted_event() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    let events = env.events().all();
+    let (contract_id, topics, _data) = events.last().expect("no events emitted");
+    assert_eq!(*contract_id, client.address);
+    assert_eq!(topics.len(), 1);
+}
+
+#[test]
+fn grant_role_emits_role_granted_event() {
+    let (env, client, admin) = setup();
+    let attestor = Address::generate(&env);
+
+    client.grant_role(&admin, &attestor, &ROLE_ATTESTOR);
+
+    let events = env.events().all();
+    assert!(!events.is_empty());
+    let (contract_id, _topics, _data) = events.last().unwrap();
+    assert_eq!(*contract_id, client.address);
+}
+
+#[test]
+fn pause_emits_paused_event() {
+    let (env, client, admin) = setup();
+    client.pause(&admin);
+
+    let events = env.events().all();
+    let (contract_id, _topics, _data) = events.last().expect("no events emitted");
+    assert_eq!(*contract_id, client.address);
+}
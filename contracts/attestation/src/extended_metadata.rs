@@ -0,0 +1,59 @@
+//! Optional currency/basis metadata attached to an attestation.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RevenueBasis {
+    Net,
+    Gross,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationMetadata {
+    pub currency_code: String,
+    pub basis: RevenueBasis,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Metadata(Address, String),
+}
+
+/// Validate `currency_code` (ISO 4217-style: alphabetic, 1-3 chars) and
+/// build metadata.
+pub fn validate_metadata(_env: &Env, currency_code: &String, is_net: bool) -> AttestationMetadata {
+    let len = currency_code.len();
+    assert!(
+        len >= 1 && len <= 3,
+        "currency_code must be 1-3 characters"
+    );
+
+    let mut buf = [0u8; 3];
+    currency_code.copy_into_slice(&mut buf[..len as usize]);
+    assert!(
+        buf[..len as usize].iter().all(u8::is_ascii_alphabetic),
+        "currency_code must be alphabetic"
+    );
+
+    AttestationMetadata {
+        currency_code: currency_code.clone(),
+        basis: if is_net {
+            RevenueBasis::Net
+        } else {
+            RevenueBasis::Gross
+        },
+    }
+}
+
+pub fn set_metadata(env: &Env, business: &Address, period: &String, metadata: &AttestationMetadata) {
+    let key = DataKey::Metadata(business.clone(), period.clone());
+    env.storage().instance().set(&key, metadata);
+}
+
+pub fn get_metadata(env: &Env, business: &Address, period: &String) -> Option<AttestationMetadata> {
+    let key = DataKey::Metadata(business.clone(), period.clone());
+    env.storage().instance().get(&key)
+}
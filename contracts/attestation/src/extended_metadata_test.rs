@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Env;
+
+fn setup() -> (Env, AttestationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+#[test]
+fn stores_currency_and_basis_metadata() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+
+    client.submit_attestation_with_metadata(
+        &business,
+        &period,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &1_700_000_000u64,
+        &1u32,
+        &String::from_str(&env, "USD"),
+        &true,
+    );
+
+    let metadata = client
+        .get_attestation_metadata(&business, &period)
+        .expect("metadata missing");
+    assert_eq!(metadata.currency_code, String::from_str(&env, "USD"));
+    assert_eq!(metadata.basis, RevenueBasis::Net);
+}
+
+#[test]
+fn rejects_currency_code_too_long() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+
+    let result = client.try_submit_attestation_with_metadata(
+        &business,
+        &period,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &1_700_000_000u64,
+        &1u32,
+        &String::from_str(&env, "DOLLAR"),
+        &true,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn rejects_non_alphabetic_currency_code() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+
+    let result = client.try_submit_attestation_with_metadata(
+        &business,
+        &period,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &1_700_000_000u64,
+        &1u32,
+        &String::from_str(&env, "123"),
+        &true,
+    );
+    assert!(result.is_err());
+}
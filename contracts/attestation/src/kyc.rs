@@ -0,0 +1,83 @@
+//! On-chain KYC/identity gating for attesting businesses.
+//!
+//! When `require_kyc_mode` is on, businesses must be `Verified` and
+//! unexpired before they can submit attestations. Verification is set by
+//! addresses holding `ROLE_KYC_PROVIDER`, a dedicated membership role
+//! separate from `ROLE_ADMIN`.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::access_control::{self, ROLE_KYC_PROVIDER};
+use crate::events;
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum KycStatus {
+    Unverified,
+    Pending,
+    Verified,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Status(Address),
+    RequireKycMode,
+}
+
+/// Set `business`'s KYC status, valid until `expiry` (ledger timestamp).
+///
+/// `caller` must authorize and hold `ROLE_KYC_PROVIDER`.
+pub fn set_kyc_status(
+    env: &Env,
+    caller: &Address,
+    business: &Address,
+    status: KycStatus,
+    expiry: u64,
+) {
+    caller.require_auth();
+    assert!(
+        access_control::has_role(env, caller, ROLE_KYC_PROVIDER),
+        "caller must have KYC_PROVIDER role"
+    );
+
+    let key = DataKey::Status(business.clone());
+    env.storage().instance().set(&key, &(status.clone(), expiry));
+
+    events::emit_kyc_status_changed(env, business, &status, expiry, caller);
+}
+
+/// Return `business`'s `(status, expiry)`. Defaults to `(Unverified, 0)`.
+pub fn get_kyc_status(env: &Env, business: &Address) -> (KycStatus, u64) {
+    let key = DataKey::Status(business.clone());
+    env.storage()
+        .instance()
+        .get(&key)
+        .unwrap_or((KycStatus::Unverified, 0))
+}
+
+pub fn set_require_kyc_mode(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::RequireKycMode, &enabled);
+}
+
+pub fn is_kyc_mode_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireKycMode)
+        .unwrap_or(false)
+}
+
+/// When KYC mode is on, panics unless `business` is `Verified` and the
+/// verification hasn't expired against the current ledger timestamp.
+pub fn require_kyc_if_enabled(env: &Env, business: &Address) {
+    if !is_kyc_mode_required(env) {
+        return;
+    }
+    let (status, expiry) = get_kyc_status(env, business);
+    assert!(status == KycStatus::Verified, "business is not KYC-verified");
+    assert!(
+        env.ledger().timestamp() < expiry,
+        "KYC verification has expired"
+    );
+}
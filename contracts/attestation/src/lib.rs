@@ -1,20 +1,32 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, Address, BytesN, Env, String, Symbol, Val, Vec,
+};
 
 // ─── Feature modules: add new `pub mod <name>;` here (one per feature) ───
 pub mod access_control;
+pub mod delegated;
 pub mod dynamic_fees;
 pub mod events;
 pub mod extended_metadata;
+pub mod kyc;
+pub mod merkle;
 pub mod multisig;
+pub mod permission_control;
+pub mod roles;
 // ─── End feature modules ───
 
 // ─── Re-exports: add new `pub use <module>::...` here if needed ───
-pub use access_control::{ROLE_ADMIN, ROLE_ATTESTOR, ROLE_BUSINESS, ROLE_OPERATOR};
+pub use access_control::{ROLE_ADMIN, ROLE_ATTESTOR, ROLE_BUSINESS, ROLE_OPERATOR, ROLE_KYC_PROVIDER};
 pub use dynamic_fees::{compute_fee, DataKey, FeeConfig};
 pub use events::{AttestationMigratedEvent, AttestationRevokedEvent, AttestationSubmittedEvent};
 pub use extended_metadata::{AttestationMetadata, RevenueBasis};
+pub use kyc::KycStatus;
 pub use multisig::{Proposal, ProposalAction, ProposalStatus};
+pub use permission_control::{
+    ROLE_FEE_MANAGER, ROLE_MULTISIG_OWNER, ROLE_PROPOSER,
+    ROLE_ADMIN as ROLE_PERMISSION_ADMIN,
+};
 // ─── End re-exports ───
 
 // ─── Test modules: add new `mod <name>_test;` here ───
@@ -23,6 +35,8 @@ mod access_control_test;
 #[cfg(test)]
 mod batch_submission_test;
 #[cfg(test)]
+mod delegated_test;
+#[cfg(test)]
 mod dynamic_fees_test;
 #[cfg(test)]
 mod events_test;
@@ -34,8 +48,6 @@ mod multisig_test;
 mod test;
 // ─── End test modules ───
 
-pub mod dispute;
-
 /// Batch attestation item for submitting multiple attestations in a single transaction.
 ///
 /// Each item represents one attestation to be submitted.
@@ -76,64 +88,135 @@ impl AttestationContract {
 
         // Grant ADMIN role to the initializing address
         access_control::grant_role(&env, &admin, ROLE_ADMIN);
+
+        // Grant every permission-control role to the initializing admin so
+        // fee/multisig administration works out of the box; deployments
+        // that want separation of duties can redelegate from here.
+        permission_control::grant_role(
+            &env,
+            &admin,
+            ROLE_PERMISSION_ADMIN
+                | permission_control::ROLE_FEE_MANAGER
+                | permission_control::ROLE_MULTISIG_OWNER
+                | permission_control::ROLE_PROPOSER,
+        );
     }
 
-    /// Initialize multisig with owners and threshold.
+    /// Grant a permission-control role to `account`.
     ///
-    /// Must be called after `initialize`. Only the admin can set up multisig.
-    pub fn initialize_multisig(env: Env, owners: Vec<Address>, threshold: u32) {
-        dynamic_fees::require_admin(&env);
-        multisig::initialize_multisig(&env, &owners, threshold);
+    /// `caller` must authorize and hold `ROLE_PERMISSION_ADMIN`.
+    pub fn grant_permission_role(env: Env, caller: Address, account: Address, role: u32) {
+        permission_control::require_role(&env, &caller, ROLE_PERMISSION_ADMIN);
+        permission_control::grant_role(&env, &account, role);
     }
 
-    // ── Admin: Fee configuration ────────────────────────────────────
+    /// Revoke a permission-control role from `account`.
+    ///
+    /// `caller` must authorize and hold `ROLE_PERMISSION_ADMIN`.
+    pub fn revoke_permission_role(env: Env, caller: Address, account: Address, role: u32) {
+        permission_control::require_role(&env, &caller, ROLE_PERMISSION_ADMIN);
+        permission_control::revoke_role(&env, &account, role);
+    }
+
+    /// Check whether `account` holds `role` in the permission-control system.
+    pub fn has_permission_role(env: Env, account: Address, role: u32) -> bool {
+        permission_control::has_role(&env, &account, role)
+    }
+
+    /// Get `account`'s full permission-control role bitmap.
+    pub fn get_permission_roles(env: Env, account: Address) -> u32 {
+        permission_control::get_roles(&env, &account)
+    }
 
-    /// Configure or update the core fee schedule.
+    /// List every address holding `role` in the permission-control system.
+    pub fn list_permission_role_members(env: Env, role: u32) -> Vec<Address> {
+        permission_control::list_role_members(&env, role)
+    }
+
+    /// Initialize multisig with owners and threshold.
     ///
-    /// * `token`    – Token contract address for fee payment.
-    /// * `collector` – Address that receives fees.
-    /// * `base_fee` – Base fee in token smallest units.
-    /// * `enabled`  – Master switch for fee collection.
-    pub fn configure_fees(
+    /// Must be called after `initialize`. `caller` must authorize and
+    /// hold `ROLE_MULTISIG_OWNER`.
+    pub fn initialize_multisig(
         env: Env,
-        token: Address,
-        collector: Address,
-        base_fee: i128,
-        enabled: bool,
+        caller: Address,
+        owners: Vec<Address>,
+        threshold: u32,
     ) {
-        let admin = dynamic_fees::require_admin(&env);
-        assert!(base_fee >= 0, "base_fee must be non-negative");
-        let config = FeeConfig {
-            token: token.clone(),
-            collector: collector.clone(),
-            base_fee,
-            enabled,
-        };
-        dynamic_fees::set_fee_config(&env, &config);
+        permission_control::require_role(&env, &caller, permission_control::ROLE_MULTISIG_OWNER);
+        multisig::initialize_multisig(&env, &owners, threshold);
+    }
 
-        // Emit event
-        events::emit_fee_config_changed(&env, &token, &collector, base_fee, enabled, &admin);
+    // ── Admin: Fee configuration ────────────────────────────────────
+
+    /// Propose a new core fee schedule: `FeeConfig`'s `token`, `collector`,
+    /// `base_fee`, and `enabled` switch.
+    ///
+    /// The config only takes effect once the proposal is approved and run
+    /// through `execute_fee_config`; `get_fee_config` keeps reading the
+    /// last committed value until then. Only multisig owners holding
+    /// `ROLE_PROPOSER` can create proposals.
+    pub fn propose_fee_config(env: Env, proposer: Address, config: FeeConfig) -> u64 {
+        permission_control::require_role(&env, &proposer, permission_control::ROLE_PROPOSER);
+        assert!(config.base_fee >= 0, "base_fee must be non-negative");
+        multisig::create_proposal(
+            &env,
+            &proposer,
+            ProposalAction::UpdateFeeConfig(
+                config.token,
+                config.collector,
+                config.base_fee,
+                config.enabled,
+            ),
+        )
     }
 
-    /// Set the discount (in basis points, 0–10 000) for a tier level.
+    /// Propose a discount (in basis points, 0–10 000) for a tier level.
+    ///
+    /// Tier discounts, volume brackets, business tiers, and fee splits all
+    /// move effective fees just as directly as `FeeConfig::base_fee`, so
+    /// they go through the same proposal/execution-delay flow rather than
+    /// a single admin-held role -- a compromised admin key alone can no
+    /// longer unilaterally raise or lower what a business pays.
     ///
     /// * Tier 0 = Standard (default for all businesses).
     /// * Tier 1 = Professional.
     /// * Tier 2 = Enterprise.
     ///
-    /// Higher tiers are allowed; the scheme is open-ended.
-    pub fn set_tier_discount(env: Env, tier: u32, discount_bps: u32) {
-        dynamic_fees::require_admin(&env);
-        dynamic_fees::set_tier_discount(&env, tier, discount_bps);
+    /// Higher tiers are allowed; the scheme is open-ended. Only multisig
+    /// owners holding `ROLE_PROPOSER` can create proposals.
+    pub fn propose_tier_discount(
+        env: Env,
+        proposer: Address,
+        tier: u32,
+        discount_bps: u32,
+    ) -> u64 {
+        permission_control::require_role(&env, &proposer, permission_control::ROLE_PROPOSER);
+        multisig::create_proposal(
+            &env,
+            &proposer,
+            ProposalAction::UpdateTierDiscount(tier, discount_bps),
+        )
     }
 
-    /// Assign a business address to a fee tier.
-    pub fn set_business_tier(env: Env, business: Address, tier: u32) {
-        dynamic_fees::require_admin(&env);
-        dynamic_fees::set_business_tier(&env, &business, tier);
+    /// Propose assigning a business address to a fee tier.
+    ///
+    /// Only multisig owners holding `ROLE_PROPOSER` can create proposals.
+    pub fn propose_business_tier(
+        env: Env,
+        proposer: Address,
+        business: Address,
+        tier: u32,
+    ) -> u64 {
+        permission_control::require_role(&env, &proposer, permission_control::ROLE_PROPOSER);
+        multisig::create_proposal(
+            &env,
+            &proposer,
+            ProposalAction::UpdateBusinessTier(business, tier),
+        )
     }
 
-    /// Set volume discount brackets.
+    /// Propose new volume discount brackets.
     ///
     /// `thresholds` and `discounts` must be equal-length vectors.
     /// Thresholds must be in strictly ascending order.
@@ -141,17 +224,48 @@ impl AttestationContract {
     ///
     /// Example: thresholds `[10, 50, 100]`, discounts `[500, 1000, 2000]`
     /// means 5 % off after 10 attestations, 10 % after 50, 20 % after 100.
-    pub fn set_volume_brackets(env: Env, thresholds: Vec<u64>, discounts: Vec<u32>) {
-        dynamic_fees::require_admin(&env);
-        dynamic_fees::set_volume_brackets(&env, &thresholds, &discounts);
+    /// Only multisig owners holding `ROLE_PROPOSER` can create proposals.
+    pub fn propose_volume_brackets(
+        env: Env,
+        proposer: Address,
+        thresholds: Vec<u64>,
+        discounts: Vec<u32>,
+    ) -> u64 {
+        permission_control::require_role(&env, &proposer, permission_control::ROLE_PROPOSER);
+        multisig::create_proposal(
+            &env,
+            &proposer,
+            ProposalAction::UpdateVolumeBrackets(thresholds, discounts),
+        )
     }
 
-    /// Toggle fee collection on or off without changing other config.
-    pub fn set_fee_enabled(env: Env, enabled: bool) {
-        dynamic_fees::require_admin(&env);
-        let mut config = dynamic_fees::get_fee_config(&env).expect("fees not configured");
-        config.enabled = enabled;
-        dynamic_fees::set_fee_config(&env, &config);
+    /// Propose weighted fee splitting across multiple collectors.
+    ///
+    /// `weights` are in basis points and must sum to exactly 10 000.
+    /// `recipients` and `weights` must be equal-length and non-empty.
+    /// Once executed, `collect_fee` pays each recipient
+    /// `fee * weight / 10 000`, assigning any rounding dust to the first
+    /// recipient. A configured split cannot be cleared; proposing
+    /// `FeeConfig::collector` as the single recipient weighted 10 000
+    /// pays the whole fee to it alone. Only multisig owners holding
+    /// `ROLE_PROPOSER` can create proposals.
+    pub fn propose_fee_splits(
+        env: Env,
+        proposer: Address,
+        recipients: Vec<Address>,
+        weights: Vec<u32>,
+    ) -> u64 {
+        permission_control::require_role(&env, &proposer, permission_control::ROLE_PROPOSER);
+        multisig::create_proposal(
+            &env,
+            &proposer,
+            ProposalAction::UpdateFeeSplits(recipients, weights),
+        )
+    }
+
+    /// Return the configured fee split `(recipients, weights)`, if any.
+    pub fn get_fee_splits(env: Env) -> Option<(Vec<Address>, Vec<u32>)> {
+        dynamic_fees::get_fee_splits(&env)
     }
 
     // ── Role-Based Access Control ───────────────────────────────────
@@ -189,6 +303,39 @@ impl AttestationContract {
         access_control::get_role_holders(&env)
     }
 
+    // ── KYC / Identity Gating ────────────────────────────────────────
+
+    /// Set a business's KYC status, valid until `expiry` (ledger timestamp).
+    ///
+    /// Caller must authorize and hold `ROLE_KYC_PROVIDER`.
+    pub fn set_kyc_status(
+        env: Env,
+        caller: Address,
+        business: Address,
+        status: KycStatus,
+        expiry: u64,
+    ) {
+        kyc::set_kyc_status(&env, &caller, &business, status, expiry);
+    }
+
+    /// Return a business's `(status, expiry)`. Defaults to `(Unverified, 0)`.
+    pub fn get_kyc_status(env: Env, business: Address) -> (KycStatus, u64) {
+        kyc::get_kyc_status(&env, &business)
+    }
+
+    /// Toggle the global KYC requirement. When on, `submit_attestation`,
+    /// `submit_attestation_with_metadata`, and `submit_attestations_batch`
+    /// require the business to be `Verified` and unexpired. Admin-only.
+    pub fn set_require_kyc_mode(env: Env, caller: Address, enabled: bool) {
+        access_control::require_admin(&env, &caller);
+        kyc::set_require_kyc_mode(&env, enabled);
+    }
+
+    /// Return whether the global KYC requirement is currently on.
+    pub fn is_kyc_mode_required(env: Env) -> bool {
+        kyc::is_kyc_mode_required(&env)
+    }
+
     // ── Pause/Unpause ───────────────────────────────────────────────
 
     /// Pause the contract. Only ADMIN or OPERATOR can pause.
@@ -317,6 +464,10 @@ impl AttestationContract {
         for i in 0..len {
             let item = items.get(i).unwrap();
 
+            // Require KYC verification (if the switch is on) before any
+            // state changes are made.
+            kyc::require_kyc_if_enabled(&env, &item.business);
+
             // Check for duplicates within the batch itself
             for j in (i + 1)..len {
                 let other_item = items.get(j).unwrap();
@@ -392,6 +543,7 @@ impl AttestationContract {
     ) {
         access_control::require_not_paused(&env);
         business.require_auth();
+        kyc::require_kyc_if_enabled(&env, &business);
 
         let key = DataKey::Attestation(business.clone(), period.clone());
         if env.storage().instance().has(&key) {
@@ -437,6 +589,7 @@ impl AttestationContract {
     ) {
         access_control::require_not_paused(&env);
         business.require_auth();
+        kyc::require_kyc_if_enabled(&env, &business);
 
         let key = DataKey::Attestation(business.clone(), period.clone());
         if env.storage().instance().has(&key) {
@@ -463,6 +616,98 @@ impl AttestationContract {
         );
     }
 
+    /// Authorize `signer_pubkey` to sign delegated attestations (via
+    /// `submit_attestation_signed`) on behalf of `business`.
+    ///
+    /// `business` must authorize the call.
+    pub fn set_authorized_signer(env: Env, business: Address, signer_pubkey: BytesN<32>) {
+        delegated::set_authorized_signer(&env, &business, &signer_pubkey);
+    }
+
+    /// Set the allowed staleness window (seconds) for the `timestamp`
+    /// argument of `submit_attestation_signed`.
+    ///
+    /// `caller` must authorize and hold `ROLE_PERMISSION_ADMIN`.
+    pub fn set_delegation_stale_window(env: Env, caller: Address, seconds: u64) {
+        permission_control::require_role(&env, &caller, ROLE_PERMISSION_ADMIN);
+        delegated::set_stale_window(&env, seconds);
+    }
+
+    /// Submit a revenue attestation on behalf of `business` using an
+    /// off-chain Ed25519 signature instead of an in-band `require_auth`,
+    /// letting a relayer post the transaction and pay its fees.
+    ///
+    /// The signed digest binds this contract's address, a domain tag,
+    /// every attestation field, and `business`'s current nonce, so a
+    /// captured signature can't be replayed or reused against another
+    /// deployment. `signer_pubkey` must match the key most recently
+    /// authorized by `business` via `set_authorized_signer`, and
+    /// `timestamp` must fall within the configured staleness window.
+    ///
+    /// Dynamic fee collection is skipped on this path: `collect_fee` moves
+    /// tokens out of `business` via a standard SEP-41 `transfer`, which
+    /// requires `business.require_auth()` on the *Soroban* envelope. A
+    /// relayer-submitted transaction never carries that authorization — only
+    /// the off-chain Ed25519 signature does — so charging a fee here would
+    /// require `business` to co-sign the transaction anyway, defeating the
+    /// entire point of delegated submission. A business that owes fees
+    /// should submit through [`Self::submit_attestation`] instead; fee
+    /// bookkeeping (tier/volume counters) is otherwise unaffected since
+    /// `increment_business_count` still runs.
+    ///
+    /// Panics if:
+    /// - The contract is paused
+    /// - An attestation already exists for the same (business, period)
+    /// - No signer is authorized for `business`, or `signer_pubkey` doesn't match
+    /// - `timestamp` is outside the allowed staleness window
+    /// - The signature doesn't verify against the computed digest
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_attestation_signed(
+        env: Env,
+        business: Address,
+        period: String,
+        merkle_root: BytesN<32>,
+        timestamp: u64,
+        version: u32,
+        signer_pubkey: BytesN<32>,
+        signature: BytesN<64>,
+    ) {
+        access_control::require_not_paused(&env);
+        kyc::require_kyc_if_enabled(&env, &business);
+
+        delegated::verify_and_consume(
+            &env,
+            &business,
+            &period,
+            &merkle_root,
+            timestamp,
+            version,
+            &signer_pubkey,
+            &signature,
+        );
+
+        let key = DataKey::Attestation(business.clone(), period.clone());
+        if env.storage().instance().has(&key) {
+            panic!("attestation already exists for this business and period");
+        }
+
+        // No fee is collected on the delegated path; see the doc comment above.
+        dynamic_fees::increment_business_count(&env, &business);
+
+        let data = (merkle_root.clone(), timestamp, version, 0i128);
+        env.storage().instance().set(&key, &data);
+
+        events::emit_attestation_submitted(
+            &env,
+            &business,
+            &period,
+            &merkle_root,
+            timestamp,
+            version,
+            0i128,
+        );
+    }
+
     /// Revoke an attestation.
     ///
     /// Only ADMIN role can revoke attestations. This marks the attestation
@@ -577,20 +822,117 @@ impl AttestationContract {
         }
     }
 
+    /// Verify that `leaf` is included in the Merkle tree committed for
+    /// `(business, period)`.
+    ///
+    /// `leaf` must already be hashed by the caller. `proof` is the
+    /// sibling path from the leaf to the root, so `proof.len()` must
+    /// equal the tree depth; bit `i` of `index` selects whether `proof[i]`
+    /// is the left or right sibling at that depth (see `merkle::recompute_root`).
+    ///
+    /// Returns `false` if the attestation is missing or revoked, or if
+    /// `index` has set bits beyond `proof.len()`.
+    pub fn verify_inclusion(
+        env: Env,
+        business: Address,
+        period: String,
+        leaf: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+        index: u64,
+    ) -> bool {
+        if Self::is_revoked(env.clone(), business.clone(), period.clone()) {
+            return false;
+        }
+        let Some((root, _ts, _ver, _fee)) = Self::get_attestation(env.clone(), business, period)
+        else {
+            return false;
+        };
+        merkle::verify_against_root(&env, &root, &leaf, &proof, index)
+    }
+
+    /// Batch form of `verify_inclusion`: verifies each `(leaf, proof, index)`
+    /// triple against the same `(business, period)` attestation.
+    ///
+    /// `leaves`, `proofs`, and `indices` must be equal-length vectors.
+    pub fn verify_inclusion_batch(
+        env: Env,
+        business: Address,
+        period: String,
+        leaves: Vec<BytesN<32>>,
+        proofs: Vec<Vec<BytesN<32>>>,
+        indices: Vec<u64>,
+    ) -> Vec<bool> {
+        assert!(
+            leaves.len() == proofs.len() && leaves.len() == indices.len(),
+            "leaves, proofs, and indices must have equal length"
+        );
+
+        let mut results = Vec::new(&env);
+
+        if Self::is_revoked(env.clone(), business.clone(), period.clone()) {
+            for _ in 0..leaves.len() {
+                results.push_back(false);
+            }
+            return results;
+        }
+
+        let root = match Self::get_attestation(env.clone(), business, period) {
+            Some((root, _ts, _ver, _fee)) => root,
+            None => {
+                for _ in 0..leaves.len() {
+                    results.push_back(false);
+                }
+                return results;
+            }
+        };
+
+        for i in 0..leaves.len() {
+            let leaf = leaves.get(i).unwrap();
+            let proof = proofs.get(i).unwrap();
+            let index = indices.get(i).unwrap();
+            results.push_back(merkle::verify_against_root(&env, &root, &leaf, &proof, index));
+        }
+        results
+    }
+
     // ── Multisig Operations ─────────────────────────────────────────
 
     /// Create a new multisig proposal.
     ///
-    /// Only multisig owners can create proposals.
+    /// Only multisig owners holding `ROLE_PROPOSER` can create proposals.
     pub fn create_proposal(env: Env, proposer: Address, action: ProposalAction) -> u64 {
+        permission_control::require_role(&env, &proposer, permission_control::ROLE_PROPOSER);
         multisig::create_proposal(&env, &proposer, action)
     }
 
+    /// Propose an arbitrary cross-contract call: invoking `function` on
+    /// `target` with `args` once the proposal is approved and executed
+    /// via `execute_call`.
+    ///
+    /// Only multisig owners holding `ROLE_PROPOSER` can create proposals.
+    pub fn propose_call(
+        env: Env,
+        proposer: Address,
+        target: Address,
+        function: Symbol,
+        args: Vec<Val>,
+    ) -> u64 {
+        permission_control::require_role(&env, &proposer, permission_control::ROLE_PROPOSER);
+        multisig::create_proposal(&env, &proposer, ProposalAction::Call(target, function, args))
+    }
+
     /// Approve a multisig proposal.
     ///
-    /// Only multisig owners can approve proposals.
+    /// Only multisig owners can approve proposals. When this approval
+    /// crosses the threshold, the proposal is queued for execution and a
+    /// `ProposalQueued` event is emitted; `execute_proposal` will then
+    /// panic until `execution_delay` seconds have passed.
     pub fn approve_proposal(env: Env, approver: Address, proposal_id: u64) {
-        multisig::approve_proposal(&env, &approver, proposal_id);
+        let newly_queued = multisig::approve_proposal(&env, &approver, proposal_id);
+        if newly_queued {
+            let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+            events::emit_proposal_queued(&env, proposal_id, proposal.approved_at.unwrap());
+        }
     }
 
     /// Reject a multisig proposal.
@@ -600,9 +942,50 @@ impl AttestationContract {
         multisig::reject_proposal(&env, &rejecter, proposal_id);
     }
 
+    /// Cancel a proposal that has reached the approval threshold and is
+    /// still pending, e.g. during its `execution_delay` cooling-off window.
+    /// Cancellation is not limited to that window: a pending proposal can
+    /// be cancelled until it is executed.
+    ///
+    /// Usable by any multisig owner, so a single compromised or
+    /// fast-moving approver can't force through a malicious action.
+    pub fn cancel_proposal(env: Env, canceller: Address, proposal_id: u64) {
+        multisig::cancel_proposal(&env, &canceller, proposal_id);
+        events::emit_proposal_cancelled(&env, proposal_id, &canceller);
+    }
+
+    /// Set the mandatory delay (seconds) between a proposal crossing the
+    /// approval threshold and its execution.
+    ///
+    /// `caller` must authorize and hold `ROLE_MULTISIG_OWNER`.
+    pub fn set_execution_delay(env: Env, caller: Address, seconds: u64) {
+        permission_control::require_role(&env, &caller, permission_control::ROLE_MULTISIG_OWNER);
+        multisig::set_execution_delay(&env, seconds);
+    }
+
+    /// Get the configured execution delay (seconds).
+    pub fn get_execution_delay(env: Env) -> u64 {
+        multisig::get_execution_delay(&env)
+    }
+
+    /// Set the default validity window (ledgers) for newly created
+    /// proposals, after which they can no longer be approved or executed.
+    ///
+    /// `caller` must authorize and hold `ROLE_MULTISIG_OWNER`.
+    pub fn set_proposal_validity_ledgers(env: Env, caller: Address, ledgers: u32) {
+        permission_control::require_role(&env, &caller, permission_control::ROLE_MULTISIG_OWNER);
+        multisig::set_proposal_validity_ledgers(&env, ledgers);
+    }
+
+    /// Get the ledger sequence at which `proposal_id` expires.
+    pub fn get_proposal_expiration(env: Env, proposal_id: u64) -> u32 {
+        multisig::get_proposal_expiration(&env, proposal_id)
+    }
+
     /// Execute an approved multisig proposal.
     ///
-    /// The proposal must have reached the approval threshold.
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period.
     pub fn execute_proposal(env: Env, executor: Address, proposal_id: u64) {
         multisig::require_owner(&env, &executor);
 
@@ -617,6 +1000,15 @@ impl AttestationContract {
 
         let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
 
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
         match proposal.action {
             ProposalAction::Pause => {
                 access_control::set_paused(&env, true);
@@ -626,14 +1018,11 @@ impl AttestationContract {
                 access_control::set_paused(&env, false);
                 events::emit_unpaused(&env, &executor);
             }
-            ProposalAction::AddOwner(ref new_owner) => {
-                multisig::add_owner(&env, new_owner);
-            }
-            ProposalAction::RemoveOwner(ref owner) => {
-                multisig::remove_owner(&env, owner);
+            ProposalAction::AddOwner(_) | ProposalAction::RemoveOwner(_) | ProposalAction::ChangeThreshold(_) => {
+                panic!("membership change proposals must be executed via execute_membership_change");
             }
-            ProposalAction::ChangeThreshold(threshold) => {
-                multisig::set_threshold(&env, threshold);
+            ProposalAction::Call(..) => {
+                panic!("call proposals must be executed via execute_call");
             }
             ProposalAction::GrantRole(ref account, role) => {
                 access_control::grant_role(&env, account, role);
@@ -643,23 +1032,302 @@ impl AttestationContract {
                 access_control::revoke_role(&env, account, role);
                 events::emit_role_revoked(&env, account, role, &executor);
             }
-            ProposalAction::UpdateFeeConfig(ref token, ref collector, base_fee, enabled) => {
-                let config = FeeConfig {
-                    token: token.clone(),
-                    collector: collector.clone(),
-                    base_fee,
-                    enabled,
-                };
-                dynamic_fees::set_fee_config(&env, &config);
-                events::emit_fee_config_changed(
-                    &env, token, collector, base_fee, enabled, &executor,
-                );
+            ProposalAction::UpdateFeeConfig(..) => {
+                panic!("fee config proposals must be executed via execute_fee_config");
+            }
+            ProposalAction::UpdateTierDiscount(..) => {
+                panic!("tier discount proposals must be executed via execute_tier_discount");
+            }
+            ProposalAction::UpdateBusinessTier(..) => {
+                panic!("business tier proposals must be executed via execute_business_tier");
+            }
+            ProposalAction::UpdateVolumeBrackets(..) => {
+                panic!("volume bracket proposals must be executed via execute_volume_brackets");
+            }
+            ProposalAction::UpdateFeeSplits(..) => {
+                panic!("fee split proposals must be executed via execute_fee_splits");
             }
         }
 
         multisig::mark_executed(&env, proposal_id);
     }
 
+    /// Execute an approved `AddOwner`/`RemoveOwner`/`ChangeThreshold`
+    /// proposal, changing the multisig owner set or threshold.
+    ///
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period. Rejects a resulting
+    /// threshold greater than the resulting owner count.
+    pub fn execute_membership_change(env: Env, executor: Address, proposal_id: u64) {
+        multisig::require_owner(&env, &executor);
+
+        assert!(
+            multisig::is_proposal_approved(&env, proposal_id),
+            "proposal not approved"
+        );
+        assert!(
+            !multisig::is_proposal_expired(&env, proposal_id),
+            "proposal has expired"
+        );
+
+        let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+        assert!(
+            multisig::is_membership_action(&proposal.action),
+            "not a membership change proposal"
+        );
+
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
+        multisig::apply_membership_change(&env, &proposal.action);
+        multisig::mark_executed(&env, proposal_id);
+    }
+
+    /// List pending proposals that change the multisig owner set or
+    /// threshold.
+    pub fn get_pending_membership_changes(env: Env) -> Vec<u64> {
+        multisig::get_pending_membership_changes(&env)
+    }
+
+    /// Execute an approved `propose_call` proposal, invoking `function` on
+    /// its `target` contract with its stored `args`.
+    ///
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period.
+    pub fn execute_call(env: Env, executor: Address, proposal_id: u64) -> Val {
+        multisig::require_owner(&env, &executor);
+
+        assert!(
+            multisig::is_proposal_approved(&env, proposal_id),
+            "proposal not approved"
+        );
+        assert!(
+            !multisig::is_proposal_expired(&env, proposal_id),
+            "proposal has expired"
+        );
+
+        let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
+        let (target, function, args) = match proposal.action {
+            ProposalAction::Call(target, function, args) => (target, function, args),
+            _ => panic!("not a call proposal"),
+        };
+
+        let result = env.invoke_contract(&target, &function, args);
+        events::emit_call_executed(&env, proposal_id, &target, &function, &executor);
+        multisig::mark_executed(&env, proposal_id);
+        result
+    }
+
+    /// Execute an approved `propose_fee_config` proposal, committing its
+    /// stored `FeeConfig` so `get_fee_config`/`get_fee_quote` pick it up.
+    ///
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period.
+    pub fn execute_fee_config(env: Env, executor: Address, proposal_id: u64) {
+        multisig::require_owner(&env, &executor);
+
+        assert!(
+            multisig::is_proposal_approved(&env, proposal_id),
+            "proposal not approved"
+        );
+        assert!(
+            !multisig::is_proposal_expired(&env, proposal_id),
+            "proposal has expired"
+        );
+
+        let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
+        let (token, collector, base_fee, enabled) = match proposal.action {
+            ProposalAction::UpdateFeeConfig(token, collector, base_fee, enabled) => {
+                (token, collector, base_fee, enabled)
+            }
+            _ => panic!("not a fee config proposal"),
+        };
+
+        let config = FeeConfig {
+            token: token.clone(),
+            collector: collector.clone(),
+            base_fee,
+            enabled,
+        };
+        dynamic_fees::set_fee_config(&env, &config);
+        events::emit_fee_config_changed(&env, &token, &collector, base_fee, enabled, &executor);
+        multisig::mark_executed(&env, proposal_id);
+    }
+
+    /// Execute an approved `propose_tier_discount` proposal.
+    ///
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period.
+    pub fn execute_tier_discount(env: Env, executor: Address, proposal_id: u64) {
+        multisig::require_owner(&env, &executor);
+
+        assert!(
+            multisig::is_proposal_approved(&env, proposal_id),
+            "proposal not approved"
+        );
+        assert!(
+            !multisig::is_proposal_expired(&env, proposal_id),
+            "proposal has expired"
+        );
+
+        let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
+        let (tier, discount_bps) = match proposal.action {
+            ProposalAction::UpdateTierDiscount(tier, discount_bps) => (tier, discount_bps),
+            _ => panic!("not a tier discount proposal"),
+        };
+
+        dynamic_fees::set_tier_discount(&env, tier, discount_bps);
+        events::emit_tier_discount_changed(&env, tier, discount_bps, &executor);
+        multisig::mark_executed(&env, proposal_id);
+    }
+
+    /// Execute an approved `propose_business_tier` proposal.
+    ///
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period.
+    pub fn execute_business_tier(env: Env, executor: Address, proposal_id: u64) {
+        multisig::require_owner(&env, &executor);
+
+        assert!(
+            multisig::is_proposal_approved(&env, proposal_id),
+            "proposal not approved"
+        );
+        assert!(
+            !multisig::is_proposal_expired(&env, proposal_id),
+            "proposal has expired"
+        );
+
+        let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
+        let (business, tier) = match proposal.action {
+            ProposalAction::UpdateBusinessTier(business, tier) => (business, tier),
+            _ => panic!("not a business tier proposal"),
+        };
+
+        dynamic_fees::set_business_tier(&env, &business, tier);
+        events::emit_business_tier_changed(&env, &business, tier, &executor);
+        multisig::mark_executed(&env, proposal_id);
+    }
+
+    /// Execute an approved `propose_volume_brackets` proposal.
+    ///
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period.
+    pub fn execute_volume_brackets(env: Env, executor: Address, proposal_id: u64) {
+        multisig::require_owner(&env, &executor);
+
+        assert!(
+            multisig::is_proposal_approved(&env, proposal_id),
+            "proposal not approved"
+        );
+        assert!(
+            !multisig::is_proposal_expired(&env, proposal_id),
+            "proposal has expired"
+        );
+
+        let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
+        let (thresholds, discounts) = match proposal.action {
+            ProposalAction::UpdateVolumeBrackets(thresholds, discounts) => (thresholds, discounts),
+            _ => panic!("not a volume brackets proposal"),
+        };
+
+        dynamic_fees::set_volume_brackets(&env, &thresholds, &discounts);
+        events::emit_volume_brackets_changed(&env, &thresholds, &discounts, &executor);
+        multisig::mark_executed(&env, proposal_id);
+    }
+
+    /// Execute an approved `propose_fee_splits` proposal.
+    ///
+    /// The proposal must have reached the approval threshold and cleared
+    /// its `execution_delay` cooling-off period.
+    pub fn execute_fee_splits(env: Env, executor: Address, proposal_id: u64) {
+        multisig::require_owner(&env, &executor);
+
+        assert!(
+            multisig::is_proposal_approved(&env, proposal_id),
+            "proposal not approved"
+        );
+        assert!(
+            !multisig::is_proposal_expired(&env, proposal_id),
+            "proposal has expired"
+        );
+
+        let proposal = multisig::get_proposal(&env, proposal_id).expect("proposal not found");
+
+        let approved_at = proposal
+            .approved_at
+            .expect("proposal has not reached the approval threshold");
+        let delay = multisig::get_execution_delay(&env);
+        assert!(
+            env.ledger().timestamp() >= approved_at + delay,
+            "proposal is still in its execution delay window"
+        );
+
+        let (recipients, weights) = match proposal.action {
+            ProposalAction::UpdateFeeSplits(recipients, weights) => (recipients, weights),
+            _ => panic!("not a fee splits proposal"),
+        };
+
+        dynamic_fees::set_fee_splits(&env, &recipients, &weights);
+        events::emit_fee_splits_changed(&env, &recipients, &weights, &executor);
+        multisig::mark_executed(&env, proposal_id);
+    }
+
     /// Get a proposal by ID.
     pub fn get_proposal(env: Env, proposal_id: u64) -> Option<Proposal> {
         multisig::get_proposal(&env, proposal_id)
@@ -670,6 +1338,11 @@ impl AttestationContract {
         multisig::get_approval_count(&env, proposal_id)
     }
 
+    /// Check whether `owner` has already approved `proposal_id`.
+    pub fn has_approved(env: Env, proposal_id: u64, owner: Address) -> bool {
+        multisig::has_approved(&env, proposal_id, &owner)
+    }
+
     /// Check if a proposal has been approved (reached threshold).
     pub fn is_proposal_approved(env: Env, proposal_id: u64) -> bool {
         multisig::is_proposal_approved(&env, proposal_id)
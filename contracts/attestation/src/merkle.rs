@@ -0,0 +1,62 @@
+//! Merkle inclusion-proof verification against stored attestation roots.
+//!
+//! `AttestationContract` only stores a single Merkle root per
+//! `(business, period)`. This module lets a caller who holds one leaf and
+//! its sibling path prove that the leaf belongs to the committed tree,
+//! without the contract ever seeing the full tree.
+
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+/// Recompute a Merkle root from a `leaf` and its sibling `proof`.
+///
+/// Bit `i` of `index` selects the order of concatenation at depth `i`:
+/// a `0` bit means `hash` is the left operand (`sha256(hash || sibling)`),
+/// a `1` bit means `hash` is the right operand (`sha256(sibling || hash)`).
+///
+/// Returns `None` if `index` has any set bit at or beyond `proof.len()`,
+/// since such a proof does not fully describe a path to the root.
+pub fn recompute_root(
+    env: &Env,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+    index: u64,
+) -> Option<BytesN<32>> {
+    let depth = proof.len();
+    if depth < 64 && (index >> depth) != 0 {
+        return None;
+    }
+
+    let mut hash = leaf.clone();
+    for i in 0..depth {
+        let sibling = proof.get(i).unwrap();
+        let bit = (index >> i) & 1;
+
+        let mut buf = [0u8; 64];
+        if bit == 0 {
+            buf[..32].copy_from_slice(&hash.to_array());
+            buf[32..].copy_from_slice(&sibling.to_array());
+        } else {
+            buf[..32].copy_from_slice(&sibling.to_array());
+            buf[32..].copy_from_slice(&hash.to_array());
+        }
+        let bytes = Bytes::from_array(env, &buf);
+        hash = env.crypto().sha256(&bytes).into();
+    }
+
+    Some(hash)
+}
+
+/// Verify that `hash` recomputed from `leaf`/`proof`/`index` matches
+/// `root`.
+pub fn verify_against_root(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+    index: u64,
+) -> bool {
+    match recompute_root(env, leaf, proof, index) {
+        Some(computed) => computed == *root,
+        None => false,
+    }
+}
@@ -0,0 +1,417 @@
+//! Owner/threshold multisig for privileged governance actions.
+//!
+//! Proposals move through `Pending -> Approved (implicit, threshold met,
+//! stamps `approved_at`) -> Executed`, or `Pending -> Rejected`, or
+//! `Approved -> Cancelled`. Execution of an approved proposal is
+//! gated behind a mandatory `execution_delay` cooling-off period measured
+//! from `approved_at`, giving owners a window to `cancel_proposal` a
+//! threshold-reached action before it takes effect. Per-owner approvals
+//! live in their own `Approval(proposal_id, owner)` keyspace rather than
+//! inline on the proposal, so reading or approving a proposal never
+//! touches a growing list; the proposal only carries a cached
+//! `approval_count`.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Val, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Owners,
+    Threshold,
+    NextProposalId,
+    Proposal(u64),
+    ExecutionDelay,
+    ProposalValidityLedgers,
+    Approval(u64, Address),
+}
+
+#[contracttype]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Pending,
+    Rejected,
+    Cancelled,
+    Executed,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ProposalAction {
+    Pause,
+    Unpause,
+    AddOwner(Address),
+    RemoveOwner(Address),
+    ChangeThreshold(u32),
+    GrantRole(Address, u32),
+    RevokeRole(Address, u32),
+    UpdateFeeConfig(Address, Address, i128, bool),
+    /// `(tier, discount_bps)`.
+    UpdateTierDiscount(u32, u32),
+    /// `(business, tier)`.
+    UpdateBusinessTier(Address, u32),
+    /// `(thresholds, discounts)`.
+    UpdateVolumeBrackets(Vec<u64>, Vec<u32>),
+    /// `(recipients, weights)`.
+    UpdateFeeSplits(Vec<Address>, Vec<u32>),
+    /// Invoke `function` on `target` with `args` once approved. Lets
+    /// owners approve and execute arbitrary cross-contract calls (token
+    /// transfers, config updates on another Veritasor contract, etc.)
+    /// without a dedicated `ProposalAction` per call shape.
+    Call(Address, Symbol, Vec<Val>),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: ProposalAction,
+    /// Cached count of distinct owner approvals. The approvals themselves
+    /// live in the separate `Approval(proposal_id, owner)` keyspace.
+    pub approval_count: u32,
+    pub status: ProposalStatus,
+    /// Ledger timestamp at which the proposal first reached the approval
+    /// threshold, or `None` if it hasn't yet. Execution is only allowed
+    /// once `execution_delay` seconds have passed since this moment.
+    pub approved_at: Option<u64>,
+    /// Ledger sequence at which the proposal was created.
+    pub created_ledger: u32,
+    /// Number of ledgers after `created_ledger` during which the proposal
+    /// may still be approved or executed.
+    pub validity_ledgers: u32,
+}
+
+pub fn get_owners(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Owners)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_threshold(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Threshold).unwrap_or(0)
+}
+
+pub fn is_owner(env: &Env, address: &Address) -> bool {
+    let owners = get_owners(env);
+    for i in 0..owners.len() {
+        if owners.get(i).unwrap() == *address {
+            return true;
+        }
+    }
+    false
+}
+
+/// Require that `caller` authorized the call and is a multisig owner.
+pub fn require_owner(env: &Env, caller: &Address) {
+    caller.require_auth();
+    assert!(is_owner(env, caller), "caller must be a multisig owner");
+}
+
+pub fn initialize_multisig(env: &Env, owners: &Vec<Address>, threshold: u32) {
+    assert!(!owners.is_empty(), "owners cannot be empty");
+    assert!(
+        threshold > 0 && threshold <= owners.len() as u32,
+        "threshold must be between 1 and the number of owners"
+    );
+    env.storage().instance().set(&DataKey::Owners, owners);
+    env.storage().instance().set(&DataKey::Threshold, &threshold);
+}
+
+pub fn add_owner(env: &Env, owner: &Address) {
+    assert!(!is_owner(env, owner), "address is already an owner");
+    let mut owners = get_owners(env);
+    owners.push_back(owner.clone());
+    env.storage().instance().set(&DataKey::Owners, &owners);
+}
+
+pub fn remove_owner(env: &Env, owner: &Address) {
+    let owners = get_owners(env);
+    let mut remaining = Vec::new(env);
+    for i in 0..owners.len() {
+        let o = owners.get(i).unwrap();
+        if o != *owner {
+            remaining.push_back(o);
+        }
+    }
+    assert!(
+        remaining.len() as u32 >= get_threshold(env),
+        "cannot remove owner below the current threshold"
+    );
+    env.storage().instance().set(&DataKey::Owners, &remaining);
+}
+
+pub fn set_threshold(env: &Env, threshold: u32) {
+    let owners = get_owners(env);
+    assert!(
+        threshold > 0 && threshold <= owners.len() as u32,
+        "threshold must be between 1 and the number of owners"
+    );
+    env.storage().instance().set(&DataKey::Threshold, &threshold);
+}
+
+fn next_proposal_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextProposalId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextProposalId, &(id + 1));
+    id
+}
+
+pub fn create_proposal(env: &Env, proposer: &Address, action: ProposalAction) -> u64 {
+    require_owner(env, proposer);
+
+    let id = next_proposal_id(env);
+    let proposal = Proposal {
+        id,
+        proposer: proposer.clone(),
+        action,
+        approval_count: 0,
+        status: ProposalStatus::Pending,
+        approved_at: None,
+        created_ledger: env.ledger().sequence(),
+        validity_ledgers: get_proposal_validity_ledgers(env),
+    };
+    env.storage().instance().set(&DataKey::Proposal(id), &proposal);
+    id
+}
+
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
+    env.storage().instance().get(&DataKey::Proposal(proposal_id))
+}
+
+/// Set the default number of ledgers a newly created proposal stays
+/// valid for, after which it can no longer be approved or executed.
+pub fn set_proposal_validity_ledgers(env: &Env, ledgers: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ProposalValidityLedgers, &ledgers);
+}
+
+/// Return the default proposal validity window (ledgers). Defaults to
+/// `u32::MAX` (effectively never expires) until an admin configures one.
+pub fn get_proposal_validity_ledgers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProposalValidityLedgers)
+        .unwrap_or(u32::MAX)
+}
+
+/// The ledger sequence at which `proposal_id` expires.
+pub fn get_proposal_expiration(env: &Env, proposal_id: u64) -> u32 {
+    let proposal = get_proposal(env, proposal_id).expect("proposal not found");
+    proposal
+        .created_ledger
+        .saturating_add(proposal.validity_ledgers)
+}
+
+/// Set the cooling-off period (seconds) that must elapse between a
+/// proposal crossing the approval threshold and its execution.
+pub fn set_execution_delay(env: &Env, seconds: u64) {
+    env.storage().instance().set(&DataKey::ExecutionDelay, &seconds);
+}
+
+pub fn get_execution_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ExecutionDelay)
+        .unwrap_or(0)
+}
+
+/// Approve a proposal, returning `true` if this approval just crossed the
+/// threshold (i.e. the proposal was newly queued for execution).
+pub fn approve_proposal(env: &Env, approver: &Address, proposal_id: u64) -> bool {
+    require_owner(env, approver);
+
+    let mut proposal = get_proposal(env, proposal_id).expect("proposal not found");
+    assert!(
+        proposal.status == ProposalStatus::Pending,
+        "proposal is not pending"
+    );
+    assert!(
+        !is_proposal_expired(env, proposal_id),
+        "proposal has expired"
+    );
+
+    if !has_approved(env, proposal_id, approver) {
+        env.storage()
+            .instance()
+            .set(&DataKey::Approval(proposal_id, approver.clone()), &true);
+        proposal.approval_count += 1;
+    }
+
+    let threshold = get_threshold(env);
+    let newly_queued = proposal.approved_at.is_none()
+        && threshold > 0
+        && proposal.approval_count >= threshold;
+    if newly_queued {
+        proposal.approved_at = Some(env.ledger().timestamp());
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Proposal(proposal_id), &proposal);
+
+    newly_queued
+}
+
+pub fn reject_proposal(env: &Env, rejecter: &Address, proposal_id: u64) {
+    rejecter.require_auth();
+
+    let mut proposal = get_proposal(env, proposal_id).expect("proposal not found");
+    assert!(
+        *rejecter == proposal.proposer || is_owner(env, rejecter),
+        "only the proposer or an owner may reject"
+    );
+    assert!(
+        proposal.status == ProposalStatus::Pending,
+        "proposal is not pending"
+    );
+
+    proposal.status = ProposalStatus::Rejected;
+    env.storage()
+        .instance()
+        .set(&DataKey::Proposal(proposal_id), &proposal);
+}
+
+/// Cancel a queued (threshold-reached) proposal that is still pending,
+/// whether or not its cooling-off window has passed. Usable by any
+/// multisig owner, not just the proposer.
+pub fn cancel_proposal(env: &Env, canceller: &Address, proposal_id: u64) {
+    require_owner(env, canceller);
+
+    let mut proposal = get_proposal(env, proposal_id).expect("proposal not found");
+    assert!(
+        proposal.status == ProposalStatus::Pending,
+        "proposal is not pending"
+    );
+    assert!(
+        proposal.approved_at.is_some(),
+        "proposal has not reached the approval threshold"
+    );
+
+    proposal.status = ProposalStatus::Cancelled;
+    env.storage()
+        .instance()
+        .set(&DataKey::Proposal(proposal_id), &proposal);
+}
+
+pub fn get_approval_count(env: &Env, proposal_id: u64) -> u32 {
+    match get_proposal(env, proposal_id) {
+        Some(proposal) => proposal.approval_count,
+        None => 0,
+    }
+}
+
+/// Whether `owner` has already approved `proposal_id`. An O(1) lookup
+/// into the dedicated approval keyspace, independent of owner set size.
+pub fn has_approved(env: &Env, proposal_id: u64, owner: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Approval(proposal_id, owner.clone()))
+        .unwrap_or(false)
+}
+
+/// Whether `proposal_id`'s approval threshold is met and it has not
+/// expired. Returns `false` for an expired proposal even if the
+/// threshold was met before expiry, and `false` once the proposal has
+/// left the `Pending` state (e.g. already executed, rejected, or
+/// cancelled) so a single approved proposal cannot be executed twice.
+pub fn is_proposal_approved(env: &Env, proposal_id: u64) -> bool {
+    if is_proposal_expired(env, proposal_id) {
+        return false;
+    }
+    let proposal = match get_proposal(env, proposal_id) {
+        Some(proposal) => proposal,
+        None => return false,
+    };
+    if proposal.status != ProposalStatus::Pending {
+        return false;
+    }
+    let threshold = get_threshold(env);
+    threshold > 0 && proposal.approval_count >= threshold
+}
+
+/// Whether `proposal_id` has passed its per-proposal validity window.
+pub fn is_proposal_expired(env: &Env, proposal_id: u64) -> bool {
+    if get_proposal(env, proposal_id).is_none() {
+        return false;
+    }
+    env.ledger().sequence() > get_proposal_expiration(env, proposal_id)
+}
+
+/// Whether `action` changes the owner set or threshold, and therefore
+/// must be executed via `execute_membership_change` rather than the
+/// generic proposal executor.
+pub fn is_membership_action(action: &ProposalAction) -> bool {
+    matches!(
+        action,
+        ProposalAction::AddOwner(_)
+            | ProposalAction::RemoveOwner(_)
+            | ProposalAction::ChangeThreshold(_)
+    )
+}
+
+fn next_proposal_id_peek(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextProposalId)
+        .unwrap_or(0)
+}
+
+/// List the IDs of pending proposals whose action changes the owner set
+/// or threshold.
+pub fn get_pending_membership_changes(env: &Env) -> Vec<u64> {
+    let mut pending = Vec::new(env);
+    let count = next_proposal_id_peek(env);
+    for id in 0..count {
+        if let Some(proposal) = get_proposal(env, id) {
+            if proposal.status == ProposalStatus::Pending && is_membership_action(&proposal.action)
+            {
+                pending.push_back(id);
+            }
+        }
+    }
+    pending
+}
+
+/// Apply an approved `AddOwner`/`RemoveOwner`/`ChangeThreshold` proposal.
+///
+/// Panics if `action` is not a membership action, or if applying it would
+/// leave the threshold greater than the resulting owner count.
+pub fn apply_membership_change(env: &Env, action: &ProposalAction) {
+    match action {
+        ProposalAction::AddOwner(new_owner) => {
+            add_owner(env, new_owner);
+        }
+        ProposalAction::RemoveOwner(owner) => {
+            let owners = get_owners(env);
+            let resulting_count = owners.iter().filter(|o| o != owner).count() as u32;
+            assert!(
+                resulting_count >= get_threshold(env),
+                "threshold would exceed the resulting owner count"
+            );
+            remove_owner(env, owner);
+        }
+        ProposalAction::ChangeThreshold(threshold) => {
+            assert!(
+                *threshold <= get_owners(env).len(),
+                "threshold would exceed the resulting owner count"
+            );
+            set_threshold(env, *threshold);
+        }
+        _ => panic!("not a membership change action"),
+    }
+}
+
+pub fn mark_executed(env: &Env, proposal_id: u64) {
+    let mut proposal = get_proposal(env, proposal_id).expect("proposal not found");
+    proposal.status = ProposalStatus::Executed;
+    env.storage()
+        .instance()
+        .set(&DataKey::Proposal(proposal_id), &proposal);
+}
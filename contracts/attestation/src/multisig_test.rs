@@ -0,0 +1,212 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Env, IntoVal};
+
+fn setup(owner_count: u32, threshold: u32) -> (Env, AttestationContractClient<'static>, Address, Vec<Address>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let mut owners = Vec::new(&env);
+    owners.push_back(admin.clone());
+    for _ in 1..owner_count {
+        let owner = Address::generate(&env);
+        client.grant_permission_role(&admin, &owner, &ROLE_MULTISIG_OWNER);
+        client.grant_permission_role(&admin, &owner, &ROLE_PROPOSER);
+        owners.push_back(owner);
+    }
+    client.initialize_multisig(&admin, &owners, &threshold);
+
+    (env, client, admin, owners)
+}
+
+#[test]
+fn proposal_queues_once_threshold_reached() {
+    let (_env, client, admin, owners) = setup(3, 2);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::Pause);
+    client.approve_proposal(&admin, &proposal_id);
+
+    assert!(!client.is_proposal_approved(&proposal_id));
+    client.approve_proposal(&owners.get(1).unwrap(), &proposal_id);
+    assert!(client.is_proposal_approved(&proposal_id));
+
+    let proposal = client.get_proposal(&proposal_id).expect("proposal missing");
+    assert_eq!(proposal.approval_count, 2);
+}
+
+#[test]
+fn double_approval_by_same_owner_does_not_double_count() {
+    let (_env, client, admin, _owners) = setup(3, 2);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::Pause);
+
+    client.approve_proposal(&admin, &proposal_id);
+    client.approve_proposal(&admin, &proposal_id);
+
+    assert_eq!(client.get_approval_count(&proposal_id), 1);
+    assert!(client.has_approved(&proposal_id, &admin));
+}
+
+#[test]
+fn execute_proposal_respects_execution_delay() {
+    let (env, client, admin, owners) = setup(2, 2);
+    client.set_execution_delay(&admin, &100);
+
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::Pause);
+    client.approve_proposal(&admin, &proposal_id);
+    client.approve_proposal(&owners.get(1).unwrap(), &proposal_id);
+
+    let result = client.try_execute_proposal(&admin, &proposal_id);
+    assert!(result.is_err());
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.execute_proposal(&admin, &proposal_id);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn expired_proposal_cannot_be_approved_or_executed() {
+    let (env, client, admin, _owners) = setup(1, 1);
+    client.set_proposal_validity_ledgers(&admin, &5);
+
+    let approved_id = client.create_proposal(&admin, &ProposalAction::Pause);
+    client.approve_proposal(&admin, &approved_id);
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::Pause);
+    env.ledger().with_mut(|l| l.sequence_number += 10);
+
+    let result = client.try_approve_proposal(&admin, &proposal_id);
+    assert!(result.is_err());
+
+    let result = client.try_execute_proposal(&admin, &approved_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn cancel_proposal_during_cooling_off_blocks_execution() {
+    let (_env, client, admin, _owners) = setup(1, 1);
+    client.set_execution_delay(&admin, &100);
+
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::Pause);
+    client.approve_proposal(&admin, &proposal_id);
+    client.cancel_proposal(&admin, &proposal_id);
+
+    let result = client.try_execute_proposal(&admin, &proposal_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn membership_change_proposal_adds_owner() {
+    let (_env, client, admin, _owners) = setup(1, 1);
+    let new_owner = Address::generate(&_env);
+
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::AddOwner(new_owner.clone()));
+    client.approve_proposal(&admin, &proposal_id);
+    client.execute_membership_change(&admin, &proposal_id);
+
+    assert!(client.is_multisig_owner(&new_owner));
+}
+
+#[test]
+fn add_owner_rejects_existing_owner() {
+    let (_env, client, admin, owners) = setup(2, 1);
+    let existing = owners.get(1).unwrap();
+
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::AddOwner(existing));
+    client.approve_proposal(&admin, &proposal_id);
+
+    let result = client.try_execute_membership_change(&admin, &proposal_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn membership_change_must_use_dedicated_entrypoint() {
+    let (_env, client, admin, _owners) = setup(1, 1);
+    let new_owner = Address::generate(&_env);
+
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::AddOwner(new_owner));
+    client.approve_proposal(&admin, &proposal_id);
+
+    let result = client.try_execute_proposal(&admin, &proposal_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn propose_and_execute_call_invokes_target_contract() {
+    let (env, client, admin, _owners) = setup(1, 1);
+
+    // `target` must be a live contract to invoke, so point it at the
+    // attestation contract's own address and call its `has_role` entrypoint.
+    let target = client.address.clone();
+
+    let function = Symbol::new(&env, "has_role");
+    let mut args: Vec<Val> = Vec::new(&env);
+    args.push_back(admin.clone().into_val(&env));
+    args.push_back(ROLE_ADMIN.into_val(&env));
+
+    let proposal_id = client.propose_call(&admin, &target, &function, &args);
+    client.approve_proposal(&admin, &proposal_id);
+    client.execute_call(&admin, &proposal_id);
+
+    let proposal = client.get_proposal(&proposal_id).expect("proposal missing");
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+}
+
+#[test]
+fn create_proposal_requires_proposer_role() {
+    let (env, client, _admin, _owners) = setup(1, 1);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_create_proposal(&outsider, &ProposalAction::Pause);
+    assert!(result.is_err());
+}
+
+#[test]
+fn executed_proposal_cannot_be_executed_again() {
+    let (_env, client, admin, _owners) = setup(1, 1);
+
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::Pause);
+    client.approve_proposal(&admin, &proposal_id);
+    client.execute_proposal(&admin, &proposal_id);
+
+    let result = client.try_execute_proposal(&admin, &proposal_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn executed_membership_change_cannot_be_executed_again() {
+    let (env, client, admin, _owners) = setup(1, 1);
+    let new_owner = Address::generate(&env);
+
+    let proposal_id = client.create_proposal(&admin, &ProposalAction::AddOwner(new_owner));
+    client.approve_proposal(&admin, &proposal_id);
+    client.execute_membership_change(&admin, &proposal_id);
+
+    let result = client.try_execute_membership_change(&admin, &proposal_id);
+    assert!(result.is_err());
+}
+
+#[test]
+fn executed_call_proposal_cannot_be_executed_again() {
+    let (env, client, admin, _owners) = setup(1, 1);
+
+    // `target` must be a live contract to invoke, so point it at the
+    // attestation contract's own address and call its `has_role` entrypoint.
+    let target = client.address.clone();
+
+    let function = Symbol::new(&env, "has_role");
+    let mut args: Vec<Val> = Vec::new(&env);
+    args.push_back(admin.clone().into_val(&env));
+    args.push_back(ROLE_ADMIN.into_val(&env));
+
+    let proposal_id = client.propose_call(&admin, &target, &function, &args);
+    client.approve_proposal(&admin, &proposal_id);
+    client.execute_call(&admin, &proposal_id);
+
+    let result = client.try_execute_call(&admin, &proposal_id);
+    assert!(result.is_err());
+}
@@ -0,0 +1,52 @@
+//! Fine-grained permission control for privileged operations.
+//!
+//! Historically every privileged entrypoint hinged on the single address
+//! returned by `dynamic_fees::get_admin`. This module lets that authority
+//! be split across addresses by role, the same way `access_control`
+//! splits the business-facing `ROLE_ADMIN`/`ROLE_ATTESTOR`/`ROLE_BUSINESS`
+//! bitmap — but scoped to internal governance and fee administration
+//! instead of attestation submission. The bitmap storage itself lives in
+//! the shared [`crate::roles`] helper, namespaced so it can't collide
+//! with `access_control`'s separate business roles.
+
+use crate::roles::{self, Namespace};
+use soroban_sdk::{Address, Env, Vec};
+
+/// May grant/revoke any permission-control role, including its own.
+pub const ROLE_ADMIN: u32 = 1 << 0;
+/// Granted to the initializing admin. No entrypoint checks this role:
+/// fee configuration, tiers, volume brackets, and splits change only
+/// through approved multisig proposals.
+pub const ROLE_FEE_MANAGER: u32 = 1 << 1;
+/// May change multisig-level settings such as the execution delay and
+/// proposal validity window.
+pub const ROLE_MULTISIG_OWNER: u32 = 1 << 2;
+/// May create new multisig proposals.
+pub const ROLE_PROPOSER: u32 = 1 << 3;
+
+pub fn get_roles(env: &Env, account: &Address) -> u32 {
+    roles::get_roles(env, &Namespace::PermissionControl, account)
+}
+
+pub fn has_role(env: &Env, account: &Address, role: u32) -> bool {
+    roles::has_role(env, &Namespace::PermissionControl, account, role)
+}
+
+pub fn grant_role(env: &Env, account: &Address, role: u32) {
+    roles::grant_role(env, &Namespace::PermissionControl, account, role);
+}
+
+pub fn revoke_role(env: &Env, account: &Address, role: u32) {
+    roles::revoke_role(env, &Namespace::PermissionControl, account, role);
+}
+
+/// List every address holding `role`.
+pub fn list_role_members(env: &Env, role: u32) -> Vec<Address> {
+    roles::list_role_members(env, &Namespace::PermissionControl, role)
+}
+
+/// Require that `caller` authorized the call and holds `role`.
+pub fn require_role(env: &Env, caller: &Address, role: u32) {
+    caller.require_auth();
+    assert!(has_role(env, caller, role), "caller lacks required role");
+}
@@ -0,0 +1,92 @@
+//! Shared per-namespace bitmap role storage.
+//!
+//! `access_control` (business-facing `ROLE_ADMIN`/`ROLE_ATTESTOR`/...) and
+//! `permission_control` (governance-facing `ROLE_FEE_MANAGER`/
+//! `ROLE_MULTISIG_OWNER`/...) both need "which roles does this address
+//! hold" bitmaps with the same grant/revoke/list semantics, but must not
+//! share storage with each other. This module holds that plumbing once,
+//! keyed by a `Namespace` tag so the two role systems stay isolated.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum Namespace {
+    AccessControl,
+    PermissionControl,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Roles(Namespace, Address),
+    RoleHolders(Namespace),
+}
+
+fn get_role_holders_list(env: &Env, ns: &Namespace) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoleHolders(ns.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_roles(env: &Env, ns: &Namespace, account: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Roles(ns.clone(), account.clone()))
+        .unwrap_or(0)
+}
+
+pub fn has_role(env: &Env, ns: &Namespace, account: &Address, role: u32) -> bool {
+    (get_roles(env, ns, account) & role) != 0
+}
+
+pub fn grant_role(env: &Env, ns: &Namespace, account: &Address, role: u32) {
+    let roles = get_roles(env, ns, account);
+    if roles == 0 {
+        let mut holders = get_role_holders_list(env, ns);
+        holders.push_back(account.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::RoleHolders(ns.clone()), &holders);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::Roles(ns.clone(), account.clone()), &(roles | role));
+}
+
+pub fn revoke_role(env: &Env, ns: &Namespace, account: &Address, role: u32) {
+    let roles = get_roles(env, ns, account) & !role;
+    env.storage()
+        .instance()
+        .set(&DataKey::Roles(ns.clone(), account.clone()), &roles);
+
+    if roles == 0 {
+        let holders = get_role_holders_list(env, ns);
+        let mut remaining = Vec::new(env);
+        for holder in holders.iter() {
+            if holder != *account {
+                remaining.push_back(holder);
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RoleHolders(ns.clone()), &remaining);
+    }
+}
+
+pub fn get_role_holders(env: &Env, ns: &Namespace) -> Vec<Address> {
+    get_role_holders_list(env, ns)
+}
+
+/// List every address holding `role` within `ns`.
+pub fn list_role_members(env: &Env, ns: &Namespace, role: u32) -> Vec<Address> {
+    let holders = get_role_holders_list(env, ns);
+    let mut members = Vec::new(env);
+    for holder in holders.iter() {
+        if has_role(env, ns, &holder, role) {
+            members.push_back(holder);
+        }
+    }
+    members
+}
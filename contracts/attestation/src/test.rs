@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Env;
+
+fn setup() -> (Env, AttestationContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(AttestationContract, ());
+    let client = AttestationContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+#[test]
+fn initialize_grants_admin_every_governance_role() {
+    let (_env, client, admin) = setup();
+    assert!(client.has_role(&admin, &ROLE_ADMIN));
+    assert!(client.has_permission_role(&admin, &ROLE_MULTISIG_OWNER));
+    assert!(client.has_permission_role(&admin, &ROLE_PROPOSER));
+    assert!(client.has_permission_role(&admin, &ROLE_FEE_MANAGER));
+}
+
+#[test]
+fn double_initialize_fails() {
+    let (env, client, _admin) = setup();
+    let other = Address::generate(&env);
+    let result = client.try_initialize(&other);
+    assert!(result.is_err());
+}
+
+#[test]
+fn kyc_mode_blocks_unverified_business() {
+    let (env, client, admin) = setup();
+    let business = Address::generate(&env);
+    let kyc_provider = Address::generate(&env);
+    client.grant_role(&admin, &kyc_provider, &ROLE_KYC_PROVIDER);
+
+    client.set_require_kyc_mode(&admin, &true);
+
+    let result = client.try_submit_attestation(
+        &business,
+        &String::from_str(&env, "2026-01"),
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &1_700_000_000u64,
+        &1u32,
+    );
+    assert!(result.is_err());
+
+    client.set_kyc_status(
+        &kyc_provider,
+        &business,
+        &KycStatus::Verified,
+        &2_000_000_000u64,
+    );
+    client.submit_attestation(
+        &business,
+        &String::from_str(&env, "2026-01"),
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &1_700_000_000u64,
+        &1u32,
+    );
+}
+
+#[test]
+fn kyc_status_defaults_to_unverified() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let (status, expiry) = client.get_kyc_status(&business);
+    assert_eq!(status, KycStatus::Unverified);
+    assert_eq!(expiry, 0);
+}
+
+#[test]
+fn verify_inclusion_accepts_a_valid_proof_and_rejects_a_bad_one() {
+    let (env, client, _admin) = setup();
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+
+    let leaf = BytesN::from_array(&env, &[1u8; 32]);
+    let sibling = BytesN::from_array(&env, &[2u8; 32]);
+
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(&leaf.to_array());
+    buf[32..].copy_from_slice(&sibling.to_array());
+    let root: BytesN<32> = env
+        .crypto()
+        .sha256(&soroban_sdk::Bytes::from_array(&env, &buf))
+        .into();
+
+    client.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    let mut proof = Vec::new(&env);
+    proof.push_back(sibling.clone());
+    assert!(client.verify_inclusion(&business, &period, &leaf, &proof, &0u64));
+
+    let wrong_leaf = BytesN::from_array(&env, &[9u8; 32]);
+    assert!(!client.verify_inclusion(&business, &period, &wrong_leaf, &proof, &0u64));
+}
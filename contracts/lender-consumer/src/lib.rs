@@ -1,14 +1,47 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
 use veritasor_attestation::AttestationContractClient;
 
+mod registry;
+mod revision;
+
+pub use registry::AttestationSource;
+
+/// Errors returned by `LenderConsumerContract` entrypoints.
+///
+/// Replaces host traps on fallible paths so lender front-ends can
+/// distinguish recoverable conditions (missing attestation, unauthorized
+/// caller) from genuine state corruption.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ConsumerError {
+    /// `initialize` has not been called yet.
+    NotInitialized = 1,
+    /// No attestation source could be resolved for the requested
+    /// business/period.
+    CoreAttestationUnset = 2,
+    /// No attestation exists for the requested (business, period).
+    AttestationNotFound = 3,
+    /// Caller is not the configured admin.
+    Unauthorized = 4,
+    /// An indexed "latest period" no longer resolves to an attestation
+    /// in the core contract; the index and core state have desynced.
+    IndexInconsistent = 5,
+    /// `revert_revenue_metrics` / `revert_dispute_status` was called with
+    /// no earlier revision to restore.
+    NoRevisionToRevert = 6,
+    /// Referenced a `source_id` that is not a registered attestation
+    /// source.
+    SourceNotFound = 7,
+    /// `initialize` was called more than once.
+    AlreadyInitialized = 8,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Admin,
-    CoreAttestation,
-    RevenueMetrics(Address, String),
-    Dispute(Address, String),
     LatestIndexedPeriod(Address),
 }
 
@@ -40,6 +73,8 @@ pub struct LenderAttestationView {
     pub fee_paid: i128,
     pub revenue: RevenueMetrics,
     pub dispute: DisputeStatus,
+    pub source_id: u32,
+    pub source_label: String,
 }
 
 #[contracttype]
@@ -52,35 +87,53 @@ pub struct BusinessSummary {
     pub latest_version: Option<u32>,
     pub latest_fee_paid: Option<i128>,
     pub latest_dispute: DisputeStatus,
+    pub latest_source_id: Option<u32>,
+    pub latest_source_label: Option<String>,
 }
 
 #[contract]
 pub struct LenderConsumerContract;
 
-fn read_admin(env: &Env) -> Address {
+fn read_admin(env: &Env) -> Result<Address, ConsumerError> {
     let key = DataKey::Admin;
     env.storage()
         .instance()
         .get(&key)
-        .expect("not initialized")
+        .ok_or(ConsumerError::NotInitialized)
 }
 
-fn require_admin(env: &Env) {
-    let admin = read_admin(env);
-    admin.require_auth();
+fn require_admin(env: &Env, caller: &Address) -> Result<(), ConsumerError> {
+    let admin = read_admin(env)?;
+    caller.require_auth();
+    if *caller != admin {
+        return Err(ConsumerError::Unauthorized);
+    }
+    Ok(())
 }
 
-fn read_core_attestation(env: &Env) -> Address {
-    let key = DataKey::CoreAttestation;
-    env.storage()
-        .instance()
-        .get(&key)
-        .expect("core attestation not set")
+/// Resolve the attestation source for `(business, period)` and return a
+/// ready-to-use client for it alongside its id and label.
+fn attestation_client_for<'a>(
+    env: &'a Env,
+    business: &Address,
+    period: &String,
+) -> Result<(AttestationContractClient<'a>, u32, String), ConsumerError> {
+    let (source_id, source) = registry::resolve_source(env, business, period)
+        .ok_or(ConsumerError::CoreAttestationUnset)?;
+    let client = AttestationContractClient::new(env, &source.address);
+    Ok((client, source_id, source.label))
 }
 
-fn attestation_client(env: &Env) -> AttestationContractClient {
-    let core = read_core_attestation(env);
-    AttestationContractClient::new(env, &core)
+/// Resolve a default attestation source for `business` when no specific
+/// period is in play (e.g. a cumulative count).
+fn attestation_client_default<'a>(
+    env: &'a Env,
+    business: &Address,
+) -> Result<(AttestationContractClient<'a>, u32, String), ConsumerError> {
+    let (source_id, source) = registry::resolve_default_source(env, business)
+        .ok_or(ConsumerError::CoreAttestationUnset)?;
+    let client = AttestationContractClient::new(env, &source.address);
+    Ok((client, source_id, source.label))
 }
 
 fn get_latest_indexed_period(env: &Env, business: &Address) -> Option<String> {
@@ -93,86 +146,101 @@ fn set_latest_indexed_period(env: &Env, business: &Address, period: &String) {
     env.storage().instance().set(&key, period);
 }
 
-fn read_revenue_metrics(env: &Env, business: &Address, period: &String) -> Option<RevenueMetrics> {
-    let key = DataKey::RevenueMetrics(business.clone(), period.clone());
-    env.storage().instance().get(&key)
-}
-
-fn write_revenue_metrics(
-    env: &Env,
-    business: &Address,
-    period: &String,
-    metrics: &RevenueMetrics,
-) {
-    let key = DataKey::RevenueMetrics(business.clone(), period.clone());
-    env.storage().instance().set(&key, metrics);
-}
-
-fn read_dispute_status(env: &Env, business: &Address, period: &String) -> Option<DisputeStatus> {
-    let key = DataKey::Dispute(business.clone(), period.clone());
-    env.storage().instance().get(&key)
-}
-
-fn write_dispute_status(
-    env: &Env,
-    business: &Address,
-    period: &String,
-    status: &DisputeStatus,
-) {
-    let key = DataKey::Dispute(business.clone(), period.clone());
-    env.storage().instance().set(&key, status);
-}
-
 #[contractimpl]
 impl LenderConsumerContract {
     /// Initialize the lender-facing consumer.
     ///
-    /// Sets the admin and the address of the core attestation contract.
-    /// The provided `admin` must authorize the call.
-    pub fn initialize(env: Env, admin: Address, core_attestation: Address) {
+    /// Sets the admin and registers `core_attestation` as the default
+    /// attestation source (labeled `"default"`). The provided `admin`
+    /// must authorize the call.
+    pub fn initialize(env: Env, admin: Address, core_attestation: Address) -> Result<(), ConsumerError> {
         let key = DataKey::Admin;
         if env.storage().instance().has(&key) {
-            panic!("already initialized");
+            return Err(ConsumerError::AlreadyInitialized);
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::CoreAttestation, &core_attestation);
+        registry::register_source(&env, &core_attestation, &String::from_str(&env, "default"));
+        Ok(())
     }
 
-    /// Update the core attestation contract address.
+    /// Register a new attestation source. Returns its `source_id`.
     ///
-    /// Only the admin may update the core contract reference.
-    pub fn set_core_attestation(env: Env, core_attestation: Address) {
-        require_admin(&env);
-        env.storage()
-            .instance()
-            .set(&DataKey::CoreAttestation, &core_attestation);
+    /// Admin-only. Lenders can aggregate attestations from several
+    /// independent attestation contracts (e.g. different accounting
+    /// integrations) by registering each as a source.
+    pub fn register_attestation_source(
+        env: Env,
+        caller: Address,
+        address: Address,
+        label: String,
+    ) -> Result<u32, ConsumerError> {
+        require_admin(&env, &caller)?;
+        Ok(registry::register_source(&env, &address, &label))
+    }
+
+    /// Remove a registered attestation source.
+    ///
+    /// Admin-only. Returns `ConsumerError::SourceNotFound` if the id is
+    /// not a currently registered source.
+    pub fn remove_attestation_source(
+        env: Env,
+        caller: Address,
+        source_id: u32,
+    ) -> Result<(), ConsumerError> {
+        require_admin(&env, &caller)?;
+        registry::remove_source(&env, source_id)
+    }
+
+    /// Pin a business to always resolve through `source_id`, bypassing
+    /// the registered-source scan.
+    ///
+    /// Admin-only. Returns `ConsumerError::SourceNotFound` if the id is
+    /// not a currently registered source.
+    pub fn set_business_source(
+        env: Env,
+        caller: Address,
+        business: Address,
+        source_id: u32,
+    ) -> Result<(), ConsumerError> {
+        require_admin(&env, &caller)?;
+        registry::set_business_override(&env, &business, source_id)
+    }
+
+    /// Return a registered attestation source by id, if it still exists.
+    pub fn get_attestation_source(env: Env, source_id: u32) -> Option<AttestationSource> {
+        registry::get_source(&env, source_id)
+    }
+
+    /// Return the source id a business is pinned to, if any.
+    pub fn get_business_source(env: Env, business: Address) -> Option<u32> {
+        registry::get_business_override(&env, &business)
     }
 
     /// Record revenue metrics for a specific attested period.
     ///
     /// This method anchors lender-facing revenue aggregates (period and
-    /// trailing sums) to an existing attestation in the core contract.
-    /// The admin must authorize the call.
+    /// trailing sums) to an existing attestation, resolved from the
+    /// business's pinned source or the registered source list. The admin
+    /// must authorize the call.
     ///
-    /// Panics if the underlying attestation does not exist.
+    /// Returns `ConsumerError::AttestationNotFound` if the underlying
+    /// attestation does not exist.
     pub fn record_revenue_metrics(
         env: Env,
+        caller: Address,
         business: Address,
         period: String,
         period_revenue: i128,
         trailing_3m_revenue: i128,
         trailing_12m_revenue: i128,
-    ) {
-        require_admin(&env);
+    ) -> Result<(), ConsumerError> {
+        require_admin(&env, &caller)?;
 
-        let client = attestation_client(&env);
+        let (client, _source_id, _label) = attestation_client_for(&env, &business, &period)
+            .map_err(|_| ConsumerError::AttestationNotFound)?;
         let att = client.get_attestation(&business, &period);
-        if att.is_none() {
-            panic!("attestation not found for business and period");
-        }
+        let att = att.ok_or(ConsumerError::AttestationNotFound)?;
 
         let metrics = RevenueMetrics {
             has_value: true,
@@ -180,40 +248,45 @@ impl LenderConsumerContract {
             trailing_3m_revenue,
             trailing_12m_revenue,
         };
-        write_revenue_metrics(&env, &business, &period, &metrics);
+        revision::append_revenue_revision(&env, &business, &period, &metrics);
 
         let latest = get_latest_indexed_period(&env, &business);
         match latest {
             None => set_latest_indexed_period(&env, &business, &period),
             Some(prev_period) => {
-                let prev_att = client.get_attestation(&business, &prev_period);
-                let (_, prev_ts, _, _) = prev_att.expect("missing latest indexed attestation");
-                let (_, ts, _, _) = att.expect("attestation disappeared");
+                let (prev_client, _prev_source_id, _prev_label) =
+                    attestation_client_for(&env, &business, &prev_period)?;
+                let prev_att = prev_client.get_attestation(&business, &prev_period);
+                let (_, prev_ts, _, _) =
+                    prev_att.ok_or(ConsumerError::IndexInconsistent)?;
+                let (_, ts, _, _) = att;
                 if ts >= prev_ts {
                     set_latest_indexed_period(&env, &business, &period);
                 }
             }
         }
+        Ok(())
     }
 
     /// Mark or clear a dispute or revocation for an attestation.
     ///
     /// Dispute statuses are surfaced to lenders without mutating the
-    /// underlying attestation in the core contract. The admin must
-    /// authorize the call.
+    /// underlying attestation. The admin must authorize the call.
     pub fn set_dispute_status(
         env: Env,
+        caller: Address,
         business: Address,
         period: String,
         is_disputed: bool,
         reason: Option<String>,
-    ) {
-        require_admin(&env);
+    ) -> Result<(), ConsumerError> {
+        require_admin(&env, &caller)?;
 
-        let client = attestation_client(&env);
+        let (client, _source_id, _label) = attestation_client_for(&env, &business, &period)
+            .map_err(|_| ConsumerError::AttestationNotFound)?;
         let att = client.get_attestation(&business, &period);
         if att.is_none() {
-            panic!("attestation not found for business and period");
+            return Err(ConsumerError::AttestationNotFound);
         }
 
         let status = DisputeStatus {
@@ -221,32 +294,96 @@ impl LenderConsumerContract {
             is_disputed,
             reason,
         };
-        write_dispute_status(&env, &business, &period, &status);
+        revision::append_dispute_revision(&env, &business, &period, &status);
+        Ok(())
+    }
+
+    /// Restore the previous revenue-metrics revision for `(business, period)`,
+    /// discarding the current one.
+    ///
+    /// Admin-only. Returns `ConsumerError::NoRevisionToRevert` if there is
+    /// no earlier revision to restore to.
+    pub fn revert_revenue_metrics(
+        env: Env,
+        caller: Address,
+        business: Address,
+        period: String,
+    ) -> Result<(), ConsumerError> {
+        require_admin(&env, &caller)?;
+        revision::revert_revenue_revision(&env, &business, &period)
+    }
+
+    /// Restore the previous dispute-status revision for `(business, period)`,
+    /// discarding the current one.
+    ///
+    /// Admin-only. Returns `ConsumerError::NoRevisionToRevert` if there is
+    /// no earlier revision to restore to.
+    pub fn revert_dispute_status(
+        env: Env,
+        caller: Address,
+        business: Address,
+        period: String,
+    ) -> Result<(), ConsumerError> {
+        require_admin(&env, &caller)?;
+        revision::revert_dispute_revision(&env, &business, &period)
+    }
+
+    /// Return the revenue metrics recorded at a specific revision index.
+    pub fn get_revenue_at(
+        env: Env,
+        business: Address,
+        period: String,
+        revision_index: u32,
+    ) -> Option<RevenueMetrics> {
+        revision::get_revenue_at(&env, &business, &period, revision_index)
+    }
+
+    /// Return the dispute status recorded at a specific revision index.
+    pub fn get_dispute_at(
+        env: Env,
+        business: Address,
+        period: String,
+        revision_index: u32,
+    ) -> Option<DisputeStatus> {
+        revision::get_dispute_at(&env, &business, &period, revision_index)
+    }
+
+    /// Return the revenue-metrics revision count for `(business, period)`;
+    /// the current revision is at index `count - 1`.
+    pub fn get_revenue_revision_count(env: Env, business: Address, period: String) -> u32 {
+        revision::get_revenue_revision_count(&env, &business, &period)
+    }
+
+    /// Return the dispute-status revision count for `(business, period)`;
+    /// the current revision is at index `count - 1`.
+    pub fn get_dispute_revision_count(env: Env, business: Address, period: String) -> u32 {
+        revision::get_dispute_revision_count(&env, &business, &period)
     }
 
     /// Return a lender-oriented view of a single attestation.
     ///
-    /// Combines raw attestation data from the core contract with
-    /// lender-specific overlays such as revenue metrics and dispute
-    /// status. Returns `None` if the attestation does not exist.
+    /// Combines raw attestation data resolved from the business's source
+    /// with lender-specific overlays such as revenue metrics and dispute
+    /// status. Returns `None` if no source has the attestation.
     pub fn get_lender_view(
         env: Env,
         business: Address,
         period: String,
     ) -> Option<LenderAttestationView> {
-        let client = attestation_client(&env);
+        let (client, source_id, source_label) =
+            attestation_client_for(&env, &business, &period).ok()?;
         let att = client.get_attestation(&business, &period);
         match att {
             None => None,
             Some((root, ts, ver, fee)) => {
-                let revenue = read_revenue_metrics(&env, &business, &period)
+                let revenue = revision::current_revenue(&env, &business, &period)
                     .unwrap_or(RevenueMetrics {
                         has_value: false,
                         period_revenue: 0,
                         trailing_3m_revenue: 0,
                         trailing_12m_revenue: 0,
                     });
-                let dispute = read_dispute_status(&env, &business, &period).unwrap_or(
+                let dispute = revision::current_dispute(&env, &business, &period).unwrap_or(
                     DisputeStatus {
                         is_known: false,
                         is_disputed: false,
@@ -262,6 +399,8 @@ impl LenderConsumerContract {
                     fee_paid: fee,
                     revenue,
                     dispute,
+                    source_id,
+                    source_label,
                 })
             }
         }
@@ -273,7 +412,7 @@ impl LenderConsumerContract {
         business: Address,
         period: String,
     ) -> RevenueMetrics {
-        read_revenue_metrics(&env, &business, &period).unwrap_or(RevenueMetrics {
+        revision::current_revenue(&env, &business, &period).unwrap_or(RevenueMetrics {
             has_value: false,
             period_revenue: 0,
             trailing_3m_revenue: 0,
@@ -287,7 +426,7 @@ impl LenderConsumerContract {
         business: Address,
         period: String,
     ) -> DisputeStatus {
-        read_dispute_status(&env, &business, &period).unwrap_or(DisputeStatus {
+        revision::current_dispute(&env, &business, &period).unwrap_or(DisputeStatus {
             is_known: false,
             is_disputed: false,
             reason: None,
@@ -296,41 +435,49 @@ impl LenderConsumerContract {
 
     /// Return a summary view for a business.
     ///
-    /// Exposes total attestation count from the core contract together
-    /// with details for the latest indexed attestation and its dispute
-    /// status.
-    pub fn get_business_summary(env: Env, business: Address) -> BusinessSummary {
-        let client = attestation_client(&env);
-        let count = client.get_business_count(&business);
-
+    /// Exposes a cumulative attestation count together with details for
+    /// the latest indexed attestation, its dispute status, and which
+    /// attestation source vouched for it.
+    pub fn get_business_summary(env: Env, business: Address) -> Result<BusinessSummary, ConsumerError> {
         let latest_period = get_latest_indexed_period(&env, &business);
         match latest_period {
-            None => BusinessSummary {
-                business,
-                attestation_count: count,
-                latest_period: None,
-                latest_timestamp: None,
-                latest_version: None,
-                latest_fee_paid: None,
-                latest_dispute: DisputeStatus {
-                    is_known: false,
-                    is_disputed: false,
-                    reason: None,
-                },
-            },
+            None => {
+                let count = match attestation_client_default(&env, &business) {
+                    Ok((client, _, _)) => client.get_business_count(&business),
+                    Err(_) => 0,
+                };
+                Ok(BusinessSummary {
+                    business,
+                    attestation_count: count,
+                    latest_period: None,
+                    latest_timestamp: None,
+                    latest_version: None,
+                    latest_fee_paid: None,
+                    latest_dispute: DisputeStatus {
+                        is_known: false,
+                        is_disputed: false,
+                        reason: None,
+                    },
+                    latest_source_id: None,
+                    latest_source_label: None,
+                })
+            }
             Some(period) => {
+                let (client, source_id, source_label) =
+                    attestation_client_for(&env, &business, &period)?;
+                let count = client.get_business_count(&business);
                 let att = client
                     .get_attestation(&business, &period)
-                    .expect("indexed period missing attestation");
+                    .ok_or(ConsumerError::IndexInconsistent)?;
                 let (_, ts, ver, fee) = att;
-                let dispute = read_dispute_status(&env, &business, &period).unwrap_or(
+                let dispute = revision::current_dispute(&env, &business, &period).unwrap_or(
                     DisputeStatus {
                         is_known: false,
                         is_disputed: false,
                         reason: None,
                     },
                 );
-                BusinessSummary {
+                Ok(BusinessSummary {
                     business,
                     attestation_count: count,
                     latest_period: Some(period),
@@ -338,7 +485,9 @@ impl LenderConsumerContract {
                     latest_version: Some(ver),
                     latest_fee_paid: Some(fee),
                     latest_dispute: dispute,
-                }
+                    latest_source_id: Some(source_id),
+                    latest_source_label: Some(source_label),
+                })
             }
         }
     }
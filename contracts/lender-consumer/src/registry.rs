@@ -0,0 +1,154 @@
+//! Multi-source attestation registry.
+//!
+//! Generalizes the single hard-coded core attestation contract into an
+//! ordered, admin-managed list of attestation sources, each independently
+//! queryable for `(business, period)` data, plus a per-business override
+//! that pins a business to one source regardless of what else is
+//! registered.
+
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use veritasor_attestation::AttestationContractClient;
+
+use crate::ConsumerError;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    AttestationSource(u32),
+    SourceIds,
+    NextSourceId,
+    BusinessSourceOverride(Address),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationSource {
+    pub address: Address,
+    pub label: String,
+}
+
+fn get_source_ids(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SourceIds)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Register a new attestation source. Returns its `source_id`.
+///
+/// Source ids are monotonically increasing and never reused, even after
+/// the source they were assigned to is removed.
+pub fn register_source(env: &Env, address: &Address, label: &String) -> u32 {
+    let source_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextSourceId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextSourceId, &(source_id + 1));
+
+    let source = AttestationSource {
+        address: address.clone(),
+        label: label.clone(),
+    };
+    env.storage()
+        .instance()
+        .set(&DataKey::AttestationSource(source_id), &source);
+
+    let mut ids = get_source_ids(env);
+    ids.push_back(source_id);
+    env.storage().instance().set(&DataKey::SourceIds, &ids);
+
+    source_id
+}
+
+/// Remove a registered attestation source.
+///
+/// Existing per-business overrides pointing at the removed source are
+/// left in place but will no longer resolve, falling through to the
+/// registered-source scan.
+pub fn remove_source(env: &Env, source_id: u32) -> Result<(), ConsumerError> {
+    let key = DataKey::AttestationSource(source_id);
+    if !env.storage().instance().has(&key) {
+        return Err(ConsumerError::SourceNotFound);
+    }
+    env.storage().instance().remove(&key);
+
+    let ids = get_source_ids(env);
+    let mut remaining = Vec::new(env);
+    for id in ids.iter() {
+        if id != source_id {
+            remaining.push_back(id);
+        }
+    }
+    env.storage().instance().set(&DataKey::SourceIds, &remaining);
+    Ok(())
+}
+
+/// Return a registered source by id, if it still exists.
+pub fn get_source(env: &Env, source_id: u32) -> Option<AttestationSource> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AttestationSource(source_id))
+}
+
+/// Pin `business` to always resolve through `source_id`.
+pub fn set_business_override(
+    env: &Env,
+    business: &Address,
+    source_id: u32,
+) -> Result<(), ConsumerError> {
+    if get_source(env, source_id).is_none() {
+        return Err(ConsumerError::SourceNotFound);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::BusinessSourceOverride(business.clone()), &source_id);
+    Ok(())
+}
+
+/// Return the source id a business is pinned to, if any.
+pub fn get_business_override(env: &Env, business: &Address) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::BusinessSourceOverride(business.clone()))
+}
+
+/// Resolve the attestation source a caller should read `(business,
+/// period)` from.
+///
+/// A business's override, when set, is used unconditionally. Otherwise
+/// registered sources are scanned in registration order and the first
+/// one holding an attestation for `(business, period)` wins.
+pub fn resolve_source(env: &Env, business: &Address, period: &String) -> Option<(u32, AttestationSource)> {
+    if let Some(source_id) = get_business_override(env, business) {
+        if let Some(source) = get_source(env, source_id) {
+            return Some((source_id, source));
+        }
+    }
+
+    for source_id in get_source_ids(env).iter() {
+        if let Some(source) = get_source(env, source_id) {
+            let client = AttestationContractClient::new(env, &source.address);
+            if client.get_attestation(business, period).is_some() {
+                return Some((source_id, source));
+            }
+        }
+    }
+    None
+}
+
+/// Resolve a default source for `business` when no specific period is in
+/// play (e.g. summary counts): the override if set, else the first
+/// registered source.
+pub fn resolve_default_source(env: &Env, business: &Address) -> Option<(u32, AttestationSource)> {
+    if let Some(source_id) = get_business_override(env, business) {
+        if let Some(source) = get_source(env, source_id) {
+            return Some((source_id, source));
+        }
+    }
+    let ids = get_source_ids(env);
+    let first_id = ids.iter().next()?;
+    get_source(env, first_id).map(|source| (first_id, source))
+}
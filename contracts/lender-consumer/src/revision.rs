@@ -0,0 +1,196 @@
+//! Append-only revision history for revenue metrics and dispute status.
+//!
+//! Every write appends a new revision rather than overwriting the prior
+//! value, mirroring the checkpoint/original-value technique used for net
+//! storage metering: each update records what existed before it so it can
+//! be reverted and the "current" value distinguished from history. The
+//! "current" view is the revision at index `count - 1`, where `count` is
+//! the per-key revision count; a revert lowers the count without erasing
+//! the discarded revision, so higher indices may still be stored.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+
+use crate::{ConsumerError, DisputeStatus, RevenueMetrics};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    RevenueRevision(Address, String, u32),
+    RevenueRevisionCount(Address, String),
+    RevenueNextIndex(Address, String),
+    DisputeRevision(Address, String, u32),
+    DisputeRevisionCount(Address, String),
+    DisputeNextIndex(Address, String),
+}
+
+fn revenue_count(env: &Env, business: &Address, period: &String) -> Option<u32> {
+    let key = DataKey::RevenueRevisionCount(business.clone(), period.clone());
+    env.storage().instance().get(&key)
+}
+
+fn set_revenue_count(env: &Env, business: &Address, period: &String, count: u32) {
+    let key = DataKey::RevenueRevisionCount(business.clone(), period.clone());
+    env.storage().instance().set(&key, &count);
+}
+
+/// Next never-before-used revenue revision index, distinct from the
+/// "current revision count" pointer so a revert followed by an append
+/// cannot land on (and clobber) a previously discarded index.
+fn revenue_next_index(env: &Env, business: &Address, period: &String) -> u32 {
+    let key = DataKey::RevenueNextIndex(business.clone(), period.clone());
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+fn set_revenue_next_index(env: &Env, business: &Address, period: &String, next_index: u32) {
+    let key = DataKey::RevenueNextIndex(business.clone(), period.clone());
+    env.storage().instance().set(&key, &next_index);
+}
+
+fn dispute_count(env: &Env, business: &Address, period: &String) -> Option<u32> {
+    let key = DataKey::DisputeRevisionCount(business.clone(), period.clone());
+    env.storage().instance().get(&key)
+}
+
+fn set_dispute_count(env: &Env, business: &Address, period: &String, count: u32) {
+    let key = DataKey::DisputeRevisionCount(business.clone(), period.clone());
+    env.storage().instance().set(&key, &count);
+}
+
+/// Next never-before-used dispute revision index, distinct from the
+/// "current revision count" pointer so a revert followed by an append
+/// cannot land on (and clobber) a previously discarded index.
+fn dispute_next_index(env: &Env, business: &Address, period: &String) -> u32 {
+    let key = DataKey::DisputeNextIndex(business.clone(), period.clone());
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+fn set_dispute_next_index(env: &Env, business: &Address, period: &String, next_index: u32) {
+    let key = DataKey::DisputeNextIndex(business.clone(), period.clone());
+    env.storage().instance().set(&key, &next_index);
+}
+
+/// Append `metrics` as the next revenue revision for `(business, period)`.
+///
+/// Returns the new revision index. Existing revisions are never mutated.
+pub fn append_revenue_revision(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    metrics: &RevenueMetrics,
+) -> u32 {
+    let next = revenue_next_index(env, business, period);
+    let key = DataKey::RevenueRevision(business.clone(), period.clone(), next);
+    env.storage().instance().set(&key, metrics);
+    set_revenue_next_index(env, business, period, next + 1);
+    set_revenue_count(env, business, period, next + 1);
+    next
+}
+
+/// Return the revenue metrics recorded at a specific revision index.
+pub fn get_revenue_at(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    revision: u32,
+) -> Option<RevenueMetrics> {
+    let key = DataKey::RevenueRevision(business.clone(), period.clone(), revision);
+    env.storage().instance().get(&key)
+}
+
+/// Return the revenue revision count for `(business, period)`: one past
+/// the index of the current revision.
+pub fn get_revenue_revision_count(env: &Env, business: &Address, period: &String) -> u32 {
+    revenue_count(env, business, period).unwrap_or(0)
+}
+
+/// Return the current revenue metrics (index `count - 1`), if any.
+pub fn current_revenue(env: &Env, business: &Address, period: &String) -> Option<RevenueMetrics> {
+    let count = get_revenue_revision_count(env, business, period);
+    if count == 0 {
+        return None;
+    }
+    get_revenue_at(env, business, period, count - 1)
+}
+
+/// Discard the current revenue revision, restoring the previous one.
+///
+/// Rejects with `ConsumerError::NoRevisionToRevert` when there is no
+/// revision before the current one (count is 0 or 1). The discarded
+/// revision's data is left in storage — only the "current" pointer moves
+/// back — so it remains retrievable via `get_revenue_at` by its index,
+/// preserving the auditable trail.
+pub fn revert_revenue_revision(
+    env: &Env,
+    business: &Address,
+    period: &String,
+) -> Result<(), ConsumerError> {
+    let count = get_revenue_revision_count(env, business, period);
+    if count < 2 {
+        return Err(ConsumerError::NoRevisionToRevert);
+    }
+    set_revenue_count(env, business, period, count - 1);
+    Ok(())
+}
+
+/// Append `status` as the next dispute revision for `(business, period)`.
+///
+/// Returns the new revision index. Existing revisions are never mutated.
+pub fn append_dispute_revision(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    status: &DisputeStatus,
+) -> u32 {
+    let next = dispute_next_index(env, business, period);
+    let key = DataKey::DisputeRevision(business.clone(), period.clone(), next);
+    env.storage().instance().set(&key, status);
+    set_dispute_next_index(env, business, period, next + 1);
+    set_dispute_count(env, business, period, next + 1);
+    next
+}
+
+/// Return the dispute status recorded at a specific revision index.
+pub fn get_dispute_at(
+    env: &Env,
+    business: &Address,
+    period: &String,
+    revision: u32,
+) -> Option<DisputeStatus> {
+    let key = DataKey::DisputeRevision(business.clone(), period.clone(), revision);
+    env.storage().instance().get(&key)
+}
+
+/// Return the dispute revision count for `(business, period)`: one past
+/// the index of the current revision.
+pub fn get_dispute_revision_count(env: &Env, business: &Address, period: &String) -> u32 {
+    dispute_count(env, business, period).unwrap_or(0)
+}
+
+/// Return the current dispute status (index `count - 1`), if any.
+pub fn current_dispute(env: &Env, business: &Address, period: &String) -> Option<DisputeStatus> {
+    let count = get_dispute_revision_count(env, business, period);
+    if count == 0 {
+        return None;
+    }
+    get_dispute_at(env, business, period, count - 1)
+}
+
+/// Discard the current dispute revision, restoring the previous one.
+///
+/// Rejects with `ConsumerError::NoRevisionToRevert` when there is no
+/// revision before the current one (count is 0 or 1). The discarded
+/// revision's data is left in storage — only the "current" pointer moves
+/// back — so it remains retrievable via `get_dispute_at` by its index,
+/// preserving the auditable trail.
+pub fn revert_dispute_revision(
+    env: &Env,
+    business: &Address,
+    period: &String,
+) -> Result<(), ConsumerError> {
+    let count = get_dispute_revision_count(env, business, period);
+    if count < 2 {
+        return Err(ConsumerError::NoRevisionToRevert);
+    }
+    set_dispute_count(env, business, period, count - 1);
+    Ok(())
+}
@@ -39,25 +39,26 @@ fn lender_view_no_attestation() {
 }
 
 #[test]
-#[should_panic(expected = "attestation not found for business and period")]
-fn record_metrics_without_attestation_panics() {
+fn record_metrics_without_attestation_returns_not_found() {
     let (env, _att, lender, _admin) = setup();
 
     let business = Address::generate(&env);
     let period = String::from_str(&env, "2026-01");
 
-    lender.record_revenue_metrics(
+    let result = lender.try_record_revenue_metrics(
+        &_admin,
         &business,
         &period,
         &1_000_000i128,
         &3_000_000i128,
         &12_000_000i128,
     );
+    assert_eq!(result, Err(Ok(ConsumerError::AttestationNotFound)));
 }
 
 #[test]
 fn record_and_query_revenue_metrics() {
-    let (env, att, lender, _admin) = setup();
+    let (env, att, lender, admin) = setup();
 
     let business = Address::generate(&env);
     let period = String::from_str(&env, "2026-01");
@@ -67,6 +68,7 @@ fn record_and_query_revenue_metrics() {
     att.submit_attestation(&business, &period, &root, &ts, &1u32);
 
     lender.record_revenue_metrics(
+        &admin,
         &business,
         &period,
         &1_000_000i128,
@@ -95,7 +97,7 @@ fn record_and_query_revenue_metrics() {
 
 #[test]
 fn dispute_and_revoke_attestation() {
-    let (env, att, lender, _admin) = setup();
+    let (env, att, lender, admin) = setup();
 
     let business = Address::generate(&env);
     let period = String::from_str(&env, "2026-02");
@@ -104,7 +106,7 @@ fn dispute_and_revoke_attestation() {
     att.submit_attestation(&business, &period, &root, &1_700_000_001u64, &1u32);
 
     let reason = String::from_str(&env, "mismatched revenue proof");
-    lender.set_dispute_status(&business, &period, &true, &Some(reason.clone()));
+    lender.set_dispute_status(&admin, &business, &period, &true, &Some(reason.clone()));
 
     let status = lender.get_dispute_status(&business, &period);
     assert!(status.is_known);
@@ -117,16 +119,228 @@ fn dispute_and_revoke_attestation() {
     assert!(view.dispute.is_known);
     assert!(view.dispute.is_disputed);
 
-    lender.set_dispute_status(&business, &period, &false, &None);
+    lender.set_dispute_status(&admin, &business, &period, &false, &None);
     let cleared = lender.get_dispute_status(&business, &period);
     assert!(cleared.is_known);
     assert!(!cleared.is_disputed);
     assert!(cleared.reason.is_none());
 }
 
+#[test]
+fn revert_revenue_metrics_restores_prior_revision() {
+    let (env, att, lender, admin) = setup();
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    lender.record_revenue_metrics(&admin, &business, &period, &1_000_000i128, &3_000_000i128, &12_000_000i128);
+    lender.record_revenue_metrics(&admin, &business, &period, &2_000_000i128, &6_000_000i128, &24_000_000i128);
+    assert_eq!(lender.get_revenue_revision_count(&business, &period), 2);
+
+    lender.revert_revenue_metrics(&admin, &business, &period);
+    assert_eq!(lender.get_revenue_revision_count(&business, &period), 1);
+
+    let current = lender.get_trailing_revenue(&business, &period);
+    assert_eq!(current.period_revenue, 1_000_000i128);
+
+    // The discarded revision is still retrievable by its index, not erased.
+    let discarded = lender
+        .get_revenue_at(&business, &period, &1u32)
+        .expect("reverted revision should still be readable");
+    assert_eq!(discarded.period_revenue, 2_000_000i128);
+}
+
+#[test]
+fn revert_revenue_metrics_without_history_fails() {
+    let (env, att, lender, admin) = setup();
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    lender.record_revenue_metrics(&admin, &business, &period, &1_000_000i128, &3_000_000i128, &12_000_000i128);
+
+    let result = lender.try_revert_revenue_metrics(&admin, &business, &period);
+    assert_eq!(result, Err(Ok(ConsumerError::NoRevisionToRevert)));
+}
+
+#[test]
+fn revert_dispute_status_restores_prior_revision() {
+    let (env, att, lender, admin) = setup();
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    let reason = String::from_str(&env, "bad data");
+    lender.set_dispute_status(&admin, &business, &period, &true, &Some(reason));
+    lender.set_dispute_status(&admin, &business, &period, &false, &None);
+    assert_eq!(lender.get_dispute_revision_count(&business, &period), 2);
+
+    lender.revert_dispute_status(&admin, &business, &period);
+    assert_eq!(lender.get_dispute_revision_count(&business, &period), 1);
+
+    let current = lender.get_dispute_status(&business, &period);
+    assert!(current.is_disputed);
+
+    // The discarded revision is still retrievable by its index, not erased.
+    let discarded = lender
+        .get_dispute_at(&business, &period, &1u32)
+        .expect("reverted revision should still be readable");
+    assert!(!discarded.is_disputed);
+}
+
+#[test]
+fn revert_then_append_revenue_metrics_preserves_discarded_revision() {
+    let (env, att, lender, admin) = setup();
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    lender.record_revenue_metrics(&admin, &business, &period, &1_000_000i128, &3_000_000i128, &12_000_000i128);
+    lender.record_revenue_metrics(&admin, &business, &period, &2_000_000i128, &6_000_000i128, &24_000_000i128);
+    lender.revert_revenue_metrics(&admin, &business, &period);
+    lender.record_revenue_metrics(&admin, &business, &period, &3_000_000i128, &9_000_000i128, &36_000_000i128);
+
+    // The reverted revision must still be readable at its original index,
+    // not overwritten by the append that followed the revert.
+    let discarded = lender
+        .get_revenue_at(&business, &period, &1u32)
+        .expect("reverted revision should still be readable");
+    assert_eq!(discarded.period_revenue, 2_000_000i128);
+
+    let current = lender.get_trailing_revenue(&business, &period);
+    assert_eq!(current.period_revenue, 3_000_000i128);
+}
+
+#[test]
+fn revert_then_append_dispute_status_preserves_discarded_revision() {
+    let (env, att, lender, admin) = setup();
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    let reason = String::from_str(&env, "bad data");
+    lender.set_dispute_status(&admin, &business, &period, &true, &Some(reason));
+    lender.set_dispute_status(&admin, &business, &period, &false, &None);
+    lender.revert_dispute_status(&admin, &business, &period);
+    let reason2 = String::from_str(&env, "resolved after review");
+    lender.set_dispute_status(&admin, &business, &period, &true, &Some(reason2.clone()));
+
+    // The reverted revision must still be readable at its original index,
+    // not overwritten by the append that followed the revert.
+    let discarded = lender
+        .get_dispute_at(&business, &period, &1u32)
+        .expect("reverted revision should still be readable");
+    assert!(!discarded.is_disputed);
+
+    let current = lender.get_dispute_status(&business, &period);
+    assert!(current.is_disputed);
+    assert_eq!(current.reason.unwrap(), reason2);
+}
+
+#[test]
+fn second_source_is_found_by_scan() {
+    let (env, _att, lender, admin) = setup();
+
+    let other_id = env.register(AttestationContract, ());
+    let other = AttestationContractClient::new(&env, &other_id);
+    other.initialize(&admin);
+    let other_source_id =
+        lender.register_attestation_source(&admin, &other_id, &String::from_str(&env, "secondary"));
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[9u8; 32]);
+    other.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    // Not present in the default source, but present in the secondary one.
+    let view = lender
+        .get_lender_view(&business, &period)
+        .expect("should resolve via the secondary source");
+    assert_eq!(view.source_id, other_source_id);
+    assert_eq!(view.source_label, String::from_str(&env, "secondary"));
+}
+
+#[test]
+fn business_override_pins_resolution_regardless_of_scan() {
+    let (env, att, lender, admin) = setup();
+
+    let other_id = env.register(AttestationContract, ());
+    let other = AttestationContractClient::new(&env, &other_id);
+    other.initialize(&admin);
+    let other_source_id =
+        lender.register_attestation_source(&admin, &other_id, &String::from_str(&env, "secondary"));
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+
+    // Attested in the default source...
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    // ...but the business is pinned to the secondary source, which has
+    // nothing for this period, so resolution must fail rather than fall
+    // back to the scan.
+    lender.set_business_source(&admin, &business, &other_source_id);
+    let view = lender.get_lender_view(&business, &period);
+    assert!(view.is_none());
+}
+
+#[test]
+fn remove_attestation_source_unknown_id_fails() {
+    let (_env, _att, lender, _admin) = setup();
+
+    let result = lender.try_remove_attestation_source(&_admin, &999u32);
+    assert_eq!(result, Err(Ok(ConsumerError::SourceNotFound)));
+}
+
+#[test]
+fn set_business_source_unknown_id_fails() {
+    let (env, _att, lender, _admin) = setup();
+
+    let business = Address::generate(&env);
+    let result = lender.try_set_business_source(&_admin, &business, &999u32);
+    assert_eq!(result, Err(Ok(ConsumerError::SourceNotFound)));
+}
+
+#[test]
+fn removed_source_override_falls_back_to_scan() {
+    let (env, att, lender, admin) = setup();
+
+    let other_id = env.register(AttestationContract, ());
+    let other = AttestationContractClient::new(&env, &other_id);
+    other.initialize(&admin);
+    let other_source_id =
+        lender.register_attestation_source(&admin, &other_id, &String::from_str(&env, "secondary"));
+
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    lender.set_business_source(&admin, &business, &other_source_id);
+    lender.remove_attestation_source(&admin, &other_source_id);
+
+    // The override now points at a removed source, so resolution falls
+    // through to the registered-source scan and finds the default source.
+    let view = lender
+        .get_lender_view(&business, &period)
+        .expect("should fall back to scanning registered sources");
+    assert_eq!(view.merkle_root, root);
+}
+
 #[test]
 fn multiple_versions_and_business_summary() {
-    let (env, att, lender, _admin) = setup();
+    let (env, att, lender, admin) = setup();
 
     let business = Address::generate(&env);
 
@@ -142,6 +356,7 @@ fn multiple_versions_and_business_summary() {
     att.submit_attestation(&business, &period2, &root2, &ts2, &2u32);
 
     lender.record_revenue_metrics(
+        &admin,
         &business,
         &period1,
         &500_000i128,
@@ -149,6 +364,7 @@ fn multiple_versions_and_business_summary() {
         &500_000i128,
     );
     lender.record_revenue_metrics(
+        &admin,
         &business,
         &period2,
         &1_000_000i128,
@@ -163,3 +379,67 @@ fn multiple_versions_and_business_summary() {
     assert_eq!(summary.latest_timestamp.unwrap(), ts2);
     assert_eq!(summary.latest_version.unwrap(), 2u32);
 }
+
+#[test]
+fn admin_only_entrypoints_reject_unauthorized_caller() {
+    let (env, att, lender, admin) = setup();
+
+    let outsider = Address::generate(&env);
+    let business = Address::generate(&env);
+    let period = String::from_str(&env, "2026-01");
+    let root = BytesN::from_array(&env, &[1u8; 32]);
+    att.submit_attestation(&business, &period, &root, &1_700_000_000u64, &1u32);
+
+    let other_id = env.register(AttestationContract, ());
+
+    assert_eq!(
+        lender.try_register_attestation_source(
+            &outsider,
+            &other_id,
+            &String::from_str(&env, "secondary"),
+        ),
+        Err(Ok(ConsumerError::Unauthorized))
+    );
+    assert_eq!(
+        lender.try_remove_attestation_source(&outsider, &0u32),
+        Err(Ok(ConsumerError::Unauthorized))
+    );
+    assert_eq!(
+        lender.try_set_business_source(&outsider, &business, &0u32),
+        Err(Ok(ConsumerError::Unauthorized))
+    );
+    assert_eq!(
+        lender.try_record_revenue_metrics(
+            &outsider,
+            &business,
+            &period,
+            &1_000_000i128,
+            &3_000_000i128,
+            &12_000_000i128,
+        ),
+        Err(Ok(ConsumerError::Unauthorized))
+    );
+    assert_eq!(
+        lender.try_set_dispute_status(&outsider, &business, &period, &true, &None),
+        Err(Ok(ConsumerError::Unauthorized))
+    );
+
+    lender.record_revenue_metrics(
+        &admin,
+        &business,
+        &period,
+        &1_000_000i128,
+        &3_000_000i128,
+        &12_000_000i128,
+    );
+    assert_eq!(
+        lender.try_revert_revenue_metrics(&outsider, &business, &period),
+        Err(Ok(ConsumerError::Unauthorized))
+    );
+
+    lender.set_dispute_status(&admin, &business, &period, &true, &None);
+    assert_eq!(
+        lender.try_revert_dispute_status(&outsider, &business, &period),
+        Err(Ok(ConsumerError::Unauthorized))
+    );
+}